@@ -0,0 +1,158 @@
+//! Fluent construction of [`DisjointRange`]s
+//!
+//! ```
+//! use disjoint_ranges::DisjointRangeBuilder;
+//!
+//! let range = DisjointRangeBuilder::new()
+//!     .add(0u8, 10)
+//!     .add(20, 30)
+//!     .add_point(40)
+//!     .subtract(5, 25)
+//!     .build()
+//!     .unwrap();
+//! assert!(range.contains(&4));
+//! assert!(!range.contains(&5));
+//! assert!(range.contains(&26));
+//! assert!(range.contains(&40));
+//! ```
+
+use crate::error::RangeError;
+use crate::ranges::{DisjointRange, UnaryRange};
+use crate::traits::Stepped;
+
+enum BuildOp<T> {
+    Add(UnaryRange<T>),
+    Subtract(UnaryRange<T>),
+}
+
+/// Builder for [`DisjointRange`]s
+///
+/// Operations are recorded in order and only applied (and melded) once, by
+/// [`DisjointRangeBuilder::build`]. The first invalid `(low, high)` pair passed to
+/// the builder is reported by `build`.
+pub struct DisjointRangeBuilder<T> {
+    ops: Vec<BuildOp<T>>,
+    error: Option<RangeError>,
+}
+
+impl<T> Default for DisjointRangeBuilder<T> {
+    fn default() -> Self {
+        Self {
+            ops: Vec::new(),
+            error: None,
+        }
+    }
+}
+
+impl<T> DisjointRangeBuilder<T>
+where
+    T: Copy + Clone + Ord + Stepped,
+{
+    /// Create a new, empty builder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn push(mut self, low: T, high: T, op: fn(UnaryRange<T>) -> BuildOp<T>) -> Self {
+        match UnaryRange::new(low, high) {
+            Some(range) => self.ops.push(op(range)),
+            None => {
+                self.error.get_or_insert(RangeError::InvertedBounds);
+            }
+        }
+        self
+    }
+
+    /// Add the values from `low` to `high` (inclusive)
+    pub fn add(self, low: T, high: T) -> Self {
+        self.push(low, high, BuildOp::Add)
+    }
+
+    /// Add a single value
+    pub fn add_point(self, val: T) -> Self {
+        self.add(val, val)
+    }
+
+    /// Remove the values from `low` to `high` (inclusive) from everything added so far
+    pub fn subtract(self, low: T, high: T) -> Self {
+        self.push(low, high, BuildOp::Subtract)
+    }
+
+    /// Apply the recorded operations, producing a sorted and melded [`DisjointRange`]
+    pub fn build(self) -> Result<DisjointRange<T>, RangeError> {
+        if let Some(err) = self.error {
+            return Err(err);
+        }
+        let mut ranges = Vec::new();
+        for op in self.ops {
+            match op {
+                BuildOp::Add(range) => ranges.push(range),
+                BuildOp::Subtract(to_remove) => {
+                    ranges = ranges
+                        .into_iter()
+                        .flat_map(|range: UnaryRange<T>| {
+                            range.without(to_remove).unwrap_or_default()
+                        })
+                        .collect();
+                }
+            }
+        }
+        Ok(DisjointRange::from_ranges(ranges))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DisjointRangeBuilder;
+    use crate::error::RangeError;
+    use crate::ranges::UnaryRange;
+
+    #[test]
+    fn test_build_melds() {
+        let range = DisjointRangeBuilder::new()
+            .add(10u8, 20)
+            .add(0, 5)
+            .add(6, 8)
+            .add_point(21)
+            .build()
+            .unwrap();
+        let ranges: Vec<_> = range.ranges_iter().collect();
+        assert_eq!(
+            vec![
+                UnaryRange::new_unchecked(0, 8),
+                UnaryRange::new_unchecked(10, 21)
+            ],
+            ranges
+        );
+    }
+
+    #[test]
+    fn test_build_applies_in_order() {
+        let range = DisjointRangeBuilder::new()
+            .add(0u8, 10)
+            .subtract(3, 4)
+            .add_point(3)
+            .build()
+            .unwrap();
+        let ranges: Vec<_> = range.ranges_iter().collect();
+        assert_eq!(
+            vec![
+                UnaryRange::new_unchecked(0, 3),
+                UnaryRange::new_unchecked(5, 10)
+            ],
+            ranges
+        );
+    }
+
+    #[test]
+    fn test_build_inverted_bounds() {
+        let res = DisjointRangeBuilder::new().add(0u8, 10).add(30, 20).build();
+        assert_eq!(Some(RangeError::InvertedBounds), res.err());
+    }
+
+    #[test]
+    fn test_build_empty() {
+        let range = DisjointRangeBuilder::<u8>::new().build().unwrap();
+        assert_eq!(0, range.ranges_iter().count());
+    }
+}
@@ -0,0 +1,152 @@
+//! Serializing [`DisjointRange`]s as compact strings
+//!
+//! With the `serde` feature enabled, a [`DisjointRange`] can be (de)serialized as the same
+//! `1-5,8,12-20` string produced by its [`Display`](std::fmt::Display) implementation and
+//! accepted by its [`FromStr`] implementation, which is much easier to read in config files
+//! than a list of `low`/`high` pairs. Either wrap it in a [`CompactRange`], or use this module
+//! with `#[serde(with = "disjoint_ranges::compact")]`:
+//!
+//! ```
+//! use disjoint_ranges::DisjointRange;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Config {
+//!     #[serde(with = "disjoint_ranges::compact")]
+//!     ports: DisjointRange<u16>,
+//! }
+//!
+//! let config: Config = serde_json::from_str(r#"{"ports": "8000-8080,22,443"}"#).unwrap();
+//! assert_eq!(r#"{"ports":"22,443,8000-8080"}"#, serde_json::to_string(&config).unwrap());
+//! ```
+//!
+//! Deserializing sorts and melds the ranges, and rejects inverted or malformed ones.
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use serde::de::{self, Deserializer, Visitor};
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::ranges::DisjointRange;
+use crate::traits::Stepped;
+
+/// A [`DisjointRange`] that (de)serializes as a compact string like `1-5,8,12-20`
+#[derive(Clone)]
+pub struct CompactRange<T>(pub DisjointRange<T>);
+
+impl<T> fmt::Debug for CompactRange<T>
+where
+    T: fmt::Debug + PartialEq,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CompactRange").field(&self.0).finish()
+    }
+}
+
+impl<T> From<DisjointRange<T>> for CompactRange<T> {
+    fn from(range: DisjointRange<T>) -> Self {
+        CompactRange(range)
+    }
+}
+
+impl<T> From<CompactRange<T>> for DisjointRange<T> {
+    fn from(CompactRange(range): CompactRange<T>) -> Self {
+        range
+    }
+}
+
+impl<T> Serialize for CompactRange<T>
+where
+    T: fmt::Display + PartialEq,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize(&self.0, serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for CompactRange<T>
+where
+    T: Clone + Ord + Stepped + FromStr,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize(deserializer).map(CompactRange)
+    }
+}
+
+/// Serialize a [`DisjointRange`] as a compact string, for use with `#[serde(with)]`
+pub fn serialize<T, S>(range: &DisjointRange<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: fmt::Display + PartialEq,
+    S: Serializer,
+{
+    serializer.collect_str(range)
+}
+
+/// Deserialize a [`DisjointRange`] from a compact string, for use with `#[serde(with)]`
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<DisjointRange<T>, D::Error>
+where
+    T: Clone + Ord + Stepped + FromStr,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_str(CompactVisitor(PhantomData))
+}
+
+struct CompactVisitor<T>(PhantomData<T>);
+
+impl<T> Visitor<'_> for CompactVisitor<T>
+where
+    T: Clone + Ord + Stepped + FromStr,
+{
+    type Value = DisjointRange<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a compact range like \"1-5,8,12-20\"")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        v.parse().map_err(E::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CompactRange;
+    use crate::ranges::{DisjointRange, UnaryRange};
+
+    #[test]
+    fn test_serialize() {
+        let orig = DisjointRange::from_bounds_unchecked([(1u8, 5), (8, 8), (12, 20)]);
+        assert_eq!(
+            r#""1-5,8,12-20""#,
+            serde_json::to_string(&CompactRange(orig)).unwrap()
+        );
+        let orig = DisjointRange::from_bounds_unchecked([(-10i8, -5), (0, 0)]);
+        assert_eq!(
+            r#""-10--5,0""#,
+            serde_json::to_string(&CompactRange(orig)).unwrap()
+        );
+        assert_eq!(
+            r#""""#,
+            serde_json::to_string(&CompactRange(DisjointRange::<u8>::empty())).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let CompactRange(orig) =
+            serde_json::from_str::<CompactRange<u8>>(r#""12-20, 1-5,8,4""#).unwrap();
+        let expected = vec![
+            UnaryRange::new_unchecked(1, 5),
+            UnaryRange::new_unchecked(8, 8),
+            UnaryRange::new_unchecked(12, 20),
+        ];
+        assert_eq!(expected, orig.ranges_iter().collect::<Vec<_>>());
+        let CompactRange(orig) = serde_json::from_str::<CompactRange<i8>>(r#""-10--5""#).unwrap();
+        assert_eq!(orig, UnaryRange::new_unchecked(-10, -5));
+        assert!(serde_json::from_str::<CompactRange<u8>>(r#""5-1""#).is_err());
+        assert!(serde_json::from_str::<CompactRange<u8>>(r#""1-300""#).is_err());
+        assert!(serde_json::from_str::<CompactRange<u8>>("[1, 5]").is_err());
+    }
+}
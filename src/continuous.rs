@@ -0,0 +1,305 @@
+//! Continuous ranges
+//!
+//! A [`ContinuousRange`] represents a contiguous range of values, like a
+//! [`UnaryRange`](crate::ranges::UnaryRange), but without requiring the values to be
+//! [`Stepped`](crate::traits::Stepped). Everything is defined by comparing endpoints, which
+//! makes it a better fit for floating-point values than
+//! [`UnaryRange`](crate::ranges::UnaryRange).
+//!
+//! Since there's no "next" or "previous" value to fall back on, each endpoint can be either
+//! inclusive or exclusive:
+//!
+//! ```text
+//!   [---------------]      (---------------)
+//!  low     <=     high    low      <      high
+//! ```
+//!
+//! Removing one range from another results in ranges with exclusive endpoints where the
+//! removed range's endpoints were inclusive, and vice versa.
+
+use std::ops::{Bound, RangeBounds};
+
+/// A single contiguous range of continuous values
+///
+/// ```text
+///   [-------)
+///  low  <  high
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ContinuousRange<T> {
+    low: T,
+    high: T,
+    low_inclusive: bool,
+    high_inclusive: bool,
+}
+
+impl<T> ContinuousRange<T>
+where
+    T: Copy + PartialOrd,
+{
+    fn new_endpoints(low: T, low_inclusive: bool, high: T, high_inclusive: bool) -> Option<Self> {
+        if low < high || (low == high && low_inclusive && high_inclusive) {
+            Some(Self {
+                low,
+                high,
+                low_inclusive,
+                high_inclusive,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Create a new [`ContinuousRange`] from `low` and `high` values, both inclusive
+    ///
+    /// Returns `None` unless `low <= high`, which also rules out `NaN`s.
+    pub fn new(low: T, high: T) -> Option<Self> {
+        Self::new_endpoints(low, true, high, true)
+    }
+
+    /// Create a new [`ContinuousRange`] from a pair of [`Bound`]s
+    ///
+    /// Returns `None` if either bound is [`Bound::Unbounded`] or if the range would be empty.
+    pub fn from_bounds(low: Bound<T>, high: Bound<T>) -> Option<Self> {
+        let (low, low_inclusive) = match low {
+            Bound::Included(low) => (low, true),
+            Bound::Excluded(low) => (low, false),
+            Bound::Unbounded => return None,
+        };
+        let (high, high_inclusive) = match high {
+            Bound::Included(high) => (high, true),
+            Bound::Excluded(high) => (high, false),
+            Bound::Unbounded => return None,
+        };
+        Self::new_endpoints(low, low_inclusive, high, high_inclusive)
+    }
+
+    /// Get `(low, high)`, regardless of whether they're inclusive
+    pub fn as_bounds(&self) -> (T, T) {
+        (self.low, self.high)
+    }
+
+    /// Test whether a value is contained within the range
+    pub fn contains(&self, val: &T) -> bool {
+        let above_low = *val > self.low || (self.low_inclusive && *val == self.low);
+        let below_high = *val < self.high || (self.high_inclusive && *val == self.high);
+        above_low && below_high
+    }
+
+    /// The range of values contained in both this range and `other`, if there are any
+    pub fn intersect(&self, other: &Self) -> Option<Self> {
+        let (low, low_inclusive) = if self.low > other.low {
+            (self.low, self.low_inclusive)
+        } else if other.low > self.low {
+            (other.low, other.low_inclusive)
+        } else {
+            (self.low, self.low_inclusive && other.low_inclusive)
+        };
+        let (high, high_inclusive) = if self.high < other.high {
+            (self.high, self.high_inclusive)
+        } else if other.high < self.high {
+            (other.high, other.high_inclusive)
+        } else {
+            (self.high, self.high_inclusive && other.high_inclusive)
+        };
+        Self::new_endpoints(low, low_inclusive, high, high_inclusive)
+    }
+
+    /// Test whether this range and `other` share any values
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.intersect(other).is_some()
+    }
+
+    /// Combine this range with `other`
+    ///
+    /// Returns a single range if the two overlap or meet (i.e. one's `high` is the other's
+    /// `low` and at least one of them is inclusive), otherwise both ranges, lowest first.
+    pub fn union(&self, other: &Self) -> Vec<Self> {
+        let (first, second) = if other.low < self.low
+            || (other.low == self.low && other.low_inclusive && !self.low_inclusive)
+        {
+            (other, self)
+        } else {
+            (self, other)
+        };
+        let meets = second.low < first.high
+            || (second.low == first.high && (second.low_inclusive || first.high_inclusive));
+        if !meets {
+            return vec![*first, *second];
+        }
+        let (high, high_inclusive) = if first.high > second.high {
+            (first.high, first.high_inclusive)
+        } else if second.high > first.high {
+            (second.high, second.high_inclusive)
+        } else {
+            (first.high, first.high_inclusive || second.high_inclusive)
+        };
+        vec![Self {
+            low: first.low,
+            low_inclusive: first.low_inclusive,
+            high,
+            high_inclusive,
+        }]
+    }
+
+    /// The current range without `other`
+    ///
+    /// Like [`UnaryRange::without`](crate::ranges::UnaryRange::without), this returns `None`
+    /// if `other` completely covers this range, otherwise a one- or two-range vector.
+    pub fn without(&self, other: &Self) -> Option<Vec<Self>> {
+        if !self.overlaps(other) {
+            return Some(vec![*self]);
+        }
+        let below = Self::new_endpoints(
+            self.low,
+            self.low_inclusive,
+            other.low,
+            !other.low_inclusive,
+        );
+        let above = Self::new_endpoints(
+            other.high,
+            !other.high_inclusive,
+            self.high,
+            self.high_inclusive,
+        );
+        let remaining: Vec<Self> = below.into_iter().chain(above).collect();
+        if remaining.is_empty() {
+            None
+        } else {
+            Some(remaining)
+        }
+    }
+}
+
+impl ContinuousRange<f32> {
+    /// Create a new [`ContinuousRange`] from finite `low` and `high` values, both inclusive
+    ///
+    /// Returns `None` if either value is infinite or `NaN`, or if `low > high`.
+    pub fn new_finite(low: f32, high: f32) -> Option<Self> {
+        if low.is_finite() && high.is_finite() {
+            Self::new(low, high)
+        } else {
+            None
+        }
+    }
+}
+
+impl ContinuousRange<f64> {
+    /// Create a new [`ContinuousRange`] from finite `low` and `high` values, both inclusive
+    ///
+    /// Returns `None` if either value is infinite or `NaN`, or if `low > high`.
+    pub fn new_finite(low: f64, high: f64) -> Option<Self> {
+        if low.is_finite() && high.is_finite() {
+            Self::new(low, high)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> RangeBounds<T> for ContinuousRange<T> {
+    fn start_bound(&self) -> Bound<&T> {
+        if self.low_inclusive {
+            Bound::Included(&self.low)
+        } else {
+            Bound::Excluded(&self.low)
+        }
+    }
+
+    fn end_bound(&self) -> Bound<&T> {
+        if self.high_inclusive {
+            Bound::Included(&self.high)
+        } else {
+            Bound::Excluded(&self.high)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ContinuousRange;
+    use std::ops::Bound;
+
+    fn half_open(low: f64, high: f64) -> ContinuousRange<f64> {
+        ContinuousRange::from_bounds(Bound::Included(low), Bound::Excluded(high)).unwrap()
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(ContinuousRange::new(0.5, 0.5).is_some());
+        assert!(ContinuousRange::new(1.0, 0.5).is_none());
+        assert!(ContinuousRange::from_bounds(Bound::Included(0.5), Bound::Excluded(0.5)).is_none());
+        assert!(ContinuousRange::from_bounds(Bound::Unbounded, Bound::Included(0.5)).is_none());
+    }
+
+    #[test]
+    fn test_new_non_finite() {
+        assert!(ContinuousRange::new(f64::NAN, 1.0).is_none());
+        assert!(ContinuousRange::new(0.0, f64::NAN).is_none());
+        assert!(ContinuousRange::new(f64::NEG_INFINITY, 1.0).is_some());
+        assert!(ContinuousRange::<f64>::new_finite(f64::NEG_INFINITY, 1.0).is_none());
+        assert!(ContinuousRange::<f32>::new_finite(0.0, f32::INFINITY).is_none());
+        assert!(ContinuousRange::<f32>::new_finite(f32::NAN, f32::NAN).is_none());
+        assert!(ContinuousRange::<f64>::new_finite(0.0, 1.0).is_some());
+    }
+
+    #[test]
+    fn test_contains() {
+        let range = half_open(0.0, 1.0);
+        assert!(range.contains(&0.0));
+        assert!(range.contains(&0.999));
+        assert!(!range.contains(&1.0));
+        assert!(!range.contains(&-0.001));
+    }
+
+    #[test]
+    fn test_intersect() {
+        let range = ContinuousRange::new(0.0, 1.0).unwrap();
+        assert_eq!(
+            Some(half_open(0.5, 1.0)),
+            half_open(0.5, 2.0).intersect(&half_open(0.0, 1.0))
+        );
+        assert_eq!(
+            ContinuousRange::new(1.0, 1.0),
+            range.intersect(&ContinuousRange::new(1.0, 2.0).unwrap())
+        );
+        assert_eq!(None, half_open(0.0, 1.0).intersect(&half_open(1.0, 2.0)));
+        assert!(!half_open(0.0, 1.0).overlaps(&half_open(1.0, 2.0)));
+        assert!(range.overlaps(&half_open(1.0, 2.0)));
+    }
+
+    #[test]
+    fn test_union() {
+        assert_eq!(
+            vec![half_open(0.0, 2.0)],
+            half_open(1.0, 2.0).union(&half_open(0.0, 1.0))
+        );
+        assert_eq!(
+            vec![half_open(0.0, 1.0), half_open(1.5, 2.0)],
+            half_open(1.5, 2.0).union(&half_open(0.0, 1.0))
+        );
+        let open_low =
+            ContinuousRange::from_bounds(Bound::Excluded(1.0), Bound::Included(2.0)).unwrap();
+        assert_eq!(2, half_open(0.0, 1.0).union(&open_low).len());
+    }
+
+    #[test]
+    fn test_without() {
+        let range = ContinuousRange::new(0.0, 2.0).unwrap();
+        let actual = range
+            .without(&ContinuousRange::new(0.5, 1.0).unwrap())
+            .unwrap();
+        assert_eq!(2, actual.len());
+        assert_eq!(Some(half_open(0.0, 0.5)), actual.first().copied());
+        assert!(!actual[1].contains(&1.0));
+        assert!(actual[1].contains(&1.001));
+        assert!(actual[1].contains(&2.0));
+        let actual = range.without(&half_open(1.0, 3.0)).unwrap();
+        assert_eq!(vec![half_open(0.0, 1.0)], actual);
+        assert_eq!(
+            None,
+            range.without(&ContinuousRange::new(-1.0, 3.0).unwrap())
+        );
+        assert_eq!(Some(vec![range]), range.without(&half_open(3.0, 4.0)));
+    }
+}
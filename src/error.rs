@@ -0,0 +1,27 @@
+//! Errors
+
+use std::fmt;
+
+/// Errors that can arise when constructing ranges
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RangeError {
+    /// A `(low, high)` pair had `low > high`
+    InvertedBounds,
+    /// A string couldn't be parsed as CIDR notation (e.g. `10.0.0.0/8`), or had host bits set
+    InvalidCidr,
+    /// A string couldn't be parsed as a compact range like `1-5,8,12-20`
+    InvalidFormat,
+}
+
+impl fmt::Display for RangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RangeError::InvertedBounds => write!(f, "range low is greater than range high"),
+            RangeError::InvalidCidr => write!(f, "invalid CIDR block"),
+            RangeError::InvalidFormat => write!(f, "invalid range format"),
+        }
+    }
+}
+
+impl std::error::Error for RangeError {}
@@ -4,16 +4,45 @@
 //! `T::MIN` and `T::MAX` and [Stepped] by `1`.
 //!
 //! Floating-point types (`f32`, `f64`) are [Bounded] by `T::NEG_INFINITY` and `T::INFINITY` and
-//! [Stepped] by `T::EPSILON`.
+//! [Stepped] by `T::EPSILON`, except that the infinities step to the nearest finite value
+//! (`T::MIN` and `T::MAX`), since adding `T::EPSILON` to them wouldn't change them.
 //!
 //! [`char`] is [Bounded] by [`char::MIN`] and [`char::MAX`]. For [Stepped], [`char`] is
-//! incremented or decremented by `1u32` while ensuring the value remains a valid [`char`].
+//! incremented or decremented by `1u32` while ensuring the value remains a valid [`char`], i.e.
+//! skipping over the surrogate code points `U+D800` to `U+DFFF`.
+//!
+//! Stepping saturates at the bounds for all of the above, so the round-trip identities
+//! described by [Stepped] hold for every value except `T::MIN_VAL` and `T::MAX_VAL`.
+//!
+//! [`Stepped::steps_between`], [`Stepped::add_steps`] and [`Stepped::sub_steps`] are computed
+//! directly for all of the above. For floating-point types they're only approximations
+//! (`(other - self) / T::EPSILON` and `self ± n * T::EPSILON`), which can differ from stepping
+//! one `T::EPSILON` at a time, since adding `T::EPSILON` to values of magnitude `2.0` or
+//! more gets rounded.
+//!
+//! Pairs `(A, B)` of [Bounded] and [Stepped] types are [Bounded] by `(A::MIN_VAL, B::MIN_VAL)`
+//! and `(A::MAX_VAL, B::MAX_VAL)` and ordered lexicographically, like an odometer with two
+//! digits. [Stepping](Stepped) only changes `B`, until it would go past one of its bounds:
+//! incrementing `(a, B::MAX_VAL)` carries into `(a.increment(), B::MIN_VAL)`, and decrementing
+//! `(a, B::MIN_VAL)` borrows from `a` to get `(a.decrement(), B::MAX_VAL)`. At the bounds of the
+//! pair itself, stepping saturates as usual. So e.g. `(page, offset)` pairs work like a flat
+//! address space where every page has the same number of offsets.
+//!
+//! [`Ipv4Addr`] and [`Ipv6Addr`] are [Bounded] by their lowest (`0.0.0.0`, `::`) and highest
+//! addresses and [Stepped] by one address, via their `u32` and `u128` representations. A
+//! range covering every [`Ipv6Addr`] holds one more value than fits in a `u128`, so prefer
+//! the `checked_count` methods over `count` for IPv6.
+//!
+//! With the `chrono` feature enabled, [`chrono::NaiveDate`] is [Bounded] by [`chrono::NaiveDate::MIN`]
+//! and [`chrono::NaiveDate::MAX`] and [Stepped] by one day.
 //!
 //! Since `f32` and `f64` only implement `PartialOrd`, they can only be used to construct
-//! [UnaryRange](crate::ranges::UnaryRange)s.
+//! [UnaryRange](crate::ranges::UnaryRange)s. [ContinuousRange](crate::continuous::ContinuousRange)
+//! is usually a better fit for them, since it doesn't rely on [Stepped] at all.
 
 use crate::traits::{Bounded, Stepped};
 use std::cmp::{max, min};
+use std::net::{Ipv4Addr, Ipv6Addr};
 
 impl Stepped for u8 {
     const STEP: u8 = 1;
@@ -23,11 +52,20 @@ impl Stepped for u8 {
     fn decrement(&self) -> Self {
         self.saturating_sub(Self::STEP)
     }
+    fn steps_between(&self, other: &Self) -> Option<u128> {
+        (other >= self).then(|| self.abs_diff(*other) as u128)
+    }
+    fn add_steps(&self, n: u128) -> Self {
+        u8::try_from(n).map_or(Self::MAX, |n| self.saturating_add(n))
+    }
+    fn sub_steps(&self, n: u128) -> Self {
+        u8::try_from(n).map_or(Self::MIN, |n| self.saturating_sub(n))
+    }
 }
 
 impl Bounded for u8 {
-    const MIN_VAL: u8 = std::u8::MIN;
-    const MAX_VAL: u8 = std::u8::MAX;
+    const MIN_VAL: u8 = u8::MIN;
+    const MAX_VAL: u8 = u8::MAX;
 }
 
 impl Stepped for u16 {
@@ -38,11 +76,20 @@ impl Stepped for u16 {
     fn decrement(&self) -> Self {
         self.saturating_sub(Self::STEP)
     }
+    fn steps_between(&self, other: &Self) -> Option<u128> {
+        (other >= self).then(|| self.abs_diff(*other) as u128)
+    }
+    fn add_steps(&self, n: u128) -> Self {
+        u16::try_from(n).map_or(Self::MAX, |n| self.saturating_add(n))
+    }
+    fn sub_steps(&self, n: u128) -> Self {
+        u16::try_from(n).map_or(Self::MIN, |n| self.saturating_sub(n))
+    }
 }
 
 impl Bounded for u16 {
-    const MIN_VAL: u16 = std::u16::MIN;
-    const MAX_VAL: u16 = std::u16::MAX;
+    const MIN_VAL: u16 = u16::MIN;
+    const MAX_VAL: u16 = u16::MAX;
 }
 impl Stepped for u32 {
     const STEP: u32 = 1;
@@ -52,11 +99,20 @@ impl Stepped for u32 {
     fn decrement(&self) -> Self {
         self.saturating_sub(Self::STEP)
     }
+    fn steps_between(&self, other: &Self) -> Option<u128> {
+        (other >= self).then(|| self.abs_diff(*other) as u128)
+    }
+    fn add_steps(&self, n: u128) -> Self {
+        u32::try_from(n).map_or(Self::MAX, |n| self.saturating_add(n))
+    }
+    fn sub_steps(&self, n: u128) -> Self {
+        u32::try_from(n).map_or(Self::MIN, |n| self.saturating_sub(n))
+    }
 }
 
 impl Bounded for u32 {
-    const MIN_VAL: u32 = std::u32::MIN;
-    const MAX_VAL: u32 = std::u32::MAX;
+    const MIN_VAL: u32 = u32::MIN;
+    const MAX_VAL: u32 = u32::MAX;
 }
 impl Stepped for u64 {
     const STEP: u64 = 1;
@@ -67,11 +123,20 @@ impl Stepped for u64 {
     fn decrement(&self) -> Self {
         self.saturating_sub(Self::STEP)
     }
+    fn steps_between(&self, other: &Self) -> Option<u128> {
+        (other >= self).then(|| self.abs_diff(*other) as u128)
+    }
+    fn add_steps(&self, n: u128) -> Self {
+        u64::try_from(n).map_or(Self::MAX, |n| self.saturating_add(n))
+    }
+    fn sub_steps(&self, n: u128) -> Self {
+        u64::try_from(n).map_or(Self::MIN, |n| self.saturating_sub(n))
+    }
 }
 
 impl Bounded for u64 {
-    const MIN_VAL: u64 = std::u64::MIN;
-    const MAX_VAL: u64 = std::u64::MAX;
+    const MIN_VAL: u64 = u64::MIN;
+    const MAX_VAL: u64 = u64::MAX;
 }
 impl Stepped for u128 {
     const STEP: u128 = 1;
@@ -81,11 +146,20 @@ impl Stepped for u128 {
     fn decrement(&self) -> Self {
         self.saturating_sub(Self::STEP)
     }
+    fn steps_between(&self, other: &Self) -> Option<u128> {
+        (other >= self).then(|| self.abs_diff(*other))
+    }
+    fn add_steps(&self, n: u128) -> Self {
+        self.saturating_add(n)
+    }
+    fn sub_steps(&self, n: u128) -> Self {
+        self.saturating_sub(n)
+    }
 }
 
 impl Bounded for u128 {
-    const MIN_VAL: u128 = std::u128::MIN;
-    const MAX_VAL: u128 = std::u128::MAX;
+    const MIN_VAL: u128 = u128::MIN;
+    const MAX_VAL: u128 = u128::MAX;
 }
 impl Stepped for usize {
     const STEP: usize = 1;
@@ -96,11 +170,20 @@ impl Stepped for usize {
     fn decrement(&self) -> Self {
         self.saturating_sub(Self::STEP)
     }
+    fn steps_between(&self, other: &Self) -> Option<u128> {
+        (other >= self).then(|| self.abs_diff(*other) as u128)
+    }
+    fn add_steps(&self, n: u128) -> Self {
+        usize::try_from(n).map_or(Self::MAX, |n| self.saturating_add(n))
+    }
+    fn sub_steps(&self, n: u128) -> Self {
+        usize::try_from(n).map_or(Self::MIN, |n| self.saturating_sub(n))
+    }
 }
 
 impl Bounded for usize {
-    const MIN_VAL: usize = std::usize::MIN;
-    const MAX_VAL: usize = std::usize::MAX;
+    const MIN_VAL: usize = usize::MIN;
+    const MAX_VAL: usize = usize::MAX;
 }
 impl Stepped for i8 {
     const STEP: i8 = 1;
@@ -110,11 +193,20 @@ impl Stepped for i8 {
     fn decrement(&self) -> Self {
         self.saturating_sub(Self::STEP)
     }
+    fn steps_between(&self, other: &Self) -> Option<u128> {
+        (other >= self).then(|| self.abs_diff(*other) as u128)
+    }
+    fn add_steps(&self, n: u128) -> Self {
+        u8::try_from(n).map_or(Self::MAX, |n| self.saturating_add_unsigned(n))
+    }
+    fn sub_steps(&self, n: u128) -> Self {
+        u8::try_from(n).map_or(Self::MIN, |n| self.saturating_sub_unsigned(n))
+    }
 }
 
 impl Bounded for i8 {
-    const MIN_VAL: i8 = std::i8::MIN;
-    const MAX_VAL: i8 = std::i8::MAX;
+    const MIN_VAL: i8 = i8::MIN;
+    const MAX_VAL: i8 = i8::MAX;
 }
 
 impl Stepped for i16 {
@@ -125,11 +217,20 @@ impl Stepped for i16 {
     fn decrement(&self) -> Self {
         self.saturating_sub(Self::STEP)
     }
+    fn steps_between(&self, other: &Self) -> Option<u128> {
+        (other >= self).then(|| self.abs_diff(*other) as u128)
+    }
+    fn add_steps(&self, n: u128) -> Self {
+        u16::try_from(n).map_or(Self::MAX, |n| self.saturating_add_unsigned(n))
+    }
+    fn sub_steps(&self, n: u128) -> Self {
+        u16::try_from(n).map_or(Self::MIN, |n| self.saturating_sub_unsigned(n))
+    }
 }
 
 impl Bounded for i16 {
-    const MIN_VAL: i16 = std::i16::MIN;
-    const MAX_VAL: i16 = std::i16::MAX;
+    const MIN_VAL: i16 = i16::MIN;
+    const MAX_VAL: i16 = i16::MAX;
 }
 impl Stepped for i32 {
     const STEP: i32 = 1;
@@ -139,11 +240,20 @@ impl Stepped for i32 {
     fn decrement(&self) -> Self {
         self.saturating_sub(Self::STEP)
     }
+    fn steps_between(&self, other: &Self) -> Option<u128> {
+        (other >= self).then(|| self.abs_diff(*other) as u128)
+    }
+    fn add_steps(&self, n: u128) -> Self {
+        u32::try_from(n).map_or(Self::MAX, |n| self.saturating_add_unsigned(n))
+    }
+    fn sub_steps(&self, n: u128) -> Self {
+        u32::try_from(n).map_or(Self::MIN, |n| self.saturating_sub_unsigned(n))
+    }
 }
 
 impl Bounded for i32 {
-    const MIN_VAL: i32 = std::i32::MIN;
-    const MAX_VAL: i32 = std::i32::MAX;
+    const MIN_VAL: i32 = i32::MIN;
+    const MAX_VAL: i32 = i32::MAX;
 }
 impl Stepped for i64 {
     const STEP: i64 = 1;
@@ -153,11 +263,20 @@ impl Stepped for i64 {
     fn decrement(&self) -> Self {
         self.saturating_sub(Self::STEP)
     }
+    fn steps_between(&self, other: &Self) -> Option<u128> {
+        (other >= self).then(|| self.abs_diff(*other) as u128)
+    }
+    fn add_steps(&self, n: u128) -> Self {
+        u64::try_from(n).map_or(Self::MAX, |n| self.saturating_add_unsigned(n))
+    }
+    fn sub_steps(&self, n: u128) -> Self {
+        u64::try_from(n).map_or(Self::MIN, |n| self.saturating_sub_unsigned(n))
+    }
 }
 
 impl Bounded for i64 {
-    const MIN_VAL: i64 = std::i64::MIN;
-    const MAX_VAL: i64 = std::i64::MAX;
+    const MIN_VAL: i64 = i64::MIN;
+    const MAX_VAL: i64 = i64::MAX;
 }
 impl Stepped for i128 {
     const STEP: i128 = 1;
@@ -167,11 +286,20 @@ impl Stepped for i128 {
     fn decrement(&self) -> Self {
         self.saturating_sub(Self::STEP)
     }
+    fn steps_between(&self, other: &Self) -> Option<u128> {
+        (other >= self).then(|| self.abs_diff(*other))
+    }
+    fn add_steps(&self, n: u128) -> Self {
+        self.saturating_add_unsigned(n)
+    }
+    fn sub_steps(&self, n: u128) -> Self {
+        self.saturating_sub_unsigned(n)
+    }
 }
 
 impl Bounded for i128 {
-    const MIN_VAL: i128 = std::i128::MIN;
-    const MAX_VAL: i128 = std::i128::MAX;
+    const MIN_VAL: i128 = i128::MIN;
+    const MAX_VAL: i128 = i128::MAX;
 }
 impl Stepped for isize {
     const STEP: isize = 1;
@@ -181,41 +309,101 @@ impl Stepped for isize {
     fn decrement(&self) -> Self {
         self.saturating_sub(Self::STEP)
     }
+    fn steps_between(&self, other: &Self) -> Option<u128> {
+        (other >= self).then(|| self.abs_diff(*other) as u128)
+    }
+    fn add_steps(&self, n: u128) -> Self {
+        usize::try_from(n).map_or(Self::MAX, |n| self.saturating_add_unsigned(n))
+    }
+    fn sub_steps(&self, n: u128) -> Self {
+        usize::try_from(n).map_or(Self::MIN, |n| self.saturating_sub_unsigned(n))
+    }
 }
 
 impl Bounded for isize {
-    const MIN_VAL: isize = std::isize::MIN;
-    const MAX_VAL: isize = std::isize::MAX;
+    const MIN_VAL: isize = isize::MIN;
+    const MAX_VAL: isize = isize::MAX;
 }
 
 impl Stepped for f32 {
     const STEP: f32 = f32::EPSILON;
     fn increment(&self) -> Self {
-        self + Self::STEP
+        // adding `EPSILON` to an infinity doesn't change it
+        if *self == Self::NEG_INFINITY {
+            Self::MIN
+        } else {
+            self + Self::STEP
+        }
     }
     fn decrement(&self) -> Self {
-        self - Self::STEP
+        if *self == Self::INFINITY {
+            Self::MAX
+        } else {
+            self - Self::STEP
+        }
+    }
+    fn steps_between(&self, other: &Self) -> Option<u128> {
+        (other >= self).then(|| ((other - self) / Self::STEP) as u128)
+    }
+    fn add_steps(&self, n: u128) -> Self {
+        if n > 0 && *self == Self::NEG_INFINITY {
+            Self::MIN.add_steps(n - 1)
+        } else {
+            self + n as f32 * Self::STEP
+        }
+    }
+    fn sub_steps(&self, n: u128) -> Self {
+        if n > 0 && *self == Self::INFINITY {
+            Self::MAX.sub_steps(n - 1)
+        } else {
+            self - n as f32 * Self::STEP
+        }
     }
 }
 
 impl Bounded for f32 {
-    const MIN_VAL: f32 = std::f32::NEG_INFINITY;
-    const MAX_VAL: f32 = std::f32::INFINITY;
+    const MIN_VAL: f32 = f32::NEG_INFINITY;
+    const MAX_VAL: f32 = f32::INFINITY;
 }
 impl Stepped for f64 {
-    const STEP: f64 = std::f64::EPSILON;
-
+    const STEP: f64 = f64::EPSILON;
     fn increment(&self) -> Self {
-        self + Self::STEP
+        // adding `EPSILON` to an infinity doesn't change it
+        if *self == Self::NEG_INFINITY {
+            Self::MIN
+        } else {
+            self + Self::STEP
+        }
     }
     fn decrement(&self) -> Self {
-        self - Self::STEP
+        if *self == Self::INFINITY {
+            Self::MAX
+        } else {
+            self - Self::STEP
+        }
+    }
+    fn steps_between(&self, other: &Self) -> Option<u128> {
+        (other >= self).then(|| ((other - self) / Self::STEP) as u128)
+    }
+    fn add_steps(&self, n: u128) -> Self {
+        if n > 0 && *self == Self::NEG_INFINITY {
+            Self::MIN.add_steps(n - 1)
+        } else {
+            self + n as f64 * Self::STEP
+        }
+    }
+    fn sub_steps(&self, n: u128) -> Self {
+        if n > 0 && *self == Self::INFINITY {
+            Self::MAX.sub_steps(n - 1)
+        } else {
+            self - n as f64 * Self::STEP
+        }
     }
 }
 
 impl Bounded for f64 {
-    const MIN_VAL: f64 = std::f64::NEG_INFINITY;
-    const MAX_VAL: f64 = std::f64::INFINITY;
+    const MIN_VAL: f64 = f64::NEG_INFINITY;
+    const MAX_VAL: f64 = f64::INFINITY;
 }
 
 impl Bounded for char {
@@ -223,12 +411,485 @@ impl Bounded for char {
     const MAX_VAL: char = char::MAX;
 }
 
+// the position of `c` among all `char`s, skipping the surrogate code points
+fn char_index(c: char) -> u32 {
+    if c < '\u{E000}' {
+        c as u32
+    } else {
+        c as u32 - (0xE000 - 0xD800)
+    }
+}
+
+fn char_from_index(idx: u32) -> char {
+    let c = if idx < 0xD800 {
+        idx
+    } else {
+        idx + (0xE000 - 0xD800)
+    };
+    char::from_u32(c).unwrap()
+}
+
 impl Stepped for char {
     const STEP: char = 1 as char;
     fn increment(&self) -> Self {
-        char::from_u32(min((*self as u32).saturating_add(1), char::MAX as u32)).unwrap()
+        match *self {
+            // skip the surrogate code points, which aren't valid `char`s
+            '\u{D7FF}' => '\u{E000}',
+            c => char::from_u32(min((c as u32).saturating_add(1), char::MAX as u32)).unwrap(),
+        }
+    }
+    fn decrement(&self) -> Self {
+        match *self {
+            '\u{E000}' => '\u{D7FF}',
+            c => char::from_u32(max((c as u32).saturating_sub(1), char::MIN as u32)).unwrap(),
+        }
+    }
+    fn steps_between(&self, other: &Self) -> Option<u128> {
+        const SURROGATES: u32 = 0xE000 - 0xD800;
+        (other >= self).then(|| {
+            let steps = *other as u32 - *self as u32;
+            if *self < '\u{E000}' && *other > '\u{D7FF}' {
+                (steps - SURROGATES) as u128
+            } else {
+                steps as u128
+            }
+        })
+    }
+    fn add_steps(&self, n: u128) -> Self {
+        let max = char_index(char::MAX) as u128;
+        char_from_index(min((char_index(*self) as u128).saturating_add(n), max) as u32)
+    }
+    fn sub_steps(&self, n: u128) -> Self {
+        char_from_index((char_index(*self) as u128).saturating_sub(n) as u32)
+    }
+}
+
+impl<A: Bounded, B: Bounded> Bounded for (A, B) {
+    const MIN_VAL: (A, B) = (A::MIN_VAL, B::MIN_VAL);
+    const MAX_VAL: (A, B) = (A::MAX_VAL, B::MAX_VAL);
+}
+
+impl<A, B> Stepped for (A, B)
+where
+    A: Bounded + Stepped + Copy,
+    B: Bounded + Stepped + Copy,
+{
+    // a step only ever changes `B` by `B::STEP` (or carries into `A`), so this is nominal
+    const STEP: (A, B) = (A::MIN_VAL, B::STEP);
+    fn increment(&self) -> Self {
+        let (a, b) = *self;
+        if b < B::MAX_VAL {
+            (a, b.increment())
+        } else if a < A::MAX_VAL {
+            (a.increment(), B::MIN_VAL)
+        } else {
+            *self
+        }
+    }
+    fn decrement(&self) -> Self {
+        let (a, b) = *self;
+        if b > B::MIN_VAL {
+            (a, b.decrement())
+        } else if a > A::MIN_VAL {
+            (a.decrement(), B::MAX_VAL)
+        } else {
+            *self
+        }
+    }
+    fn steps_between(&self, other: &Self) -> Option<u128> {
+        let ((a1, b1), (a2, b2)) = (*self, *other);
+        if other < self {
+            return None;
+        }
+        let a_steps = a1.steps_between(&a2)?;
+        if a_steps == 0 {
+            return b1.steps_between(&b2);
+        }
+        // up to the end of `a1`, carry into the next `A`, then every full run of `B`s in
+        // between, then up to `b2`
+        let b_span = B::MIN_VAL.steps_between(&B::MAX_VAL)?.saturating_add(1);
+        let steps = b1
+            .steps_between(&B::MAX_VAL)?
+            .saturating_add(1)
+            .saturating_add((a_steps - 1).saturating_mul(b_span))
+            .saturating_add(B::MIN_VAL.steps_between(&b2)?);
+        Some(steps)
+    }
+    fn add_steps(&self, n: u128) -> Self {
+        let (a, b) = *self;
+        let to_end = b.steps_between(&B::MAX_VAL).unwrap_or(0);
+        if n <= to_end {
+            return (a, b.add_steps(n));
+        }
+        // carry into the next `A`, then skip over as many full runs of `B`s as needed
+        let n = n - to_end - 1;
+        let b_span = B::MIN_VAL
+            .steps_between(&B::MAX_VAL)
+            .unwrap_or(0)
+            .saturating_add(1);
+        let carry = (n / b_span).saturating_add(1);
+        if a.steps_between(&A::MAX_VAL).unwrap_or(0) < carry {
+            Self::MAX_VAL
+        } else {
+            (a.add_steps(carry), B::MIN_VAL.add_steps(n % b_span))
+        }
+    }
+    fn sub_steps(&self, n: u128) -> Self {
+        let (a, b) = *self;
+        let to_start = B::MIN_VAL.steps_between(&b).unwrap_or(0);
+        if n <= to_start {
+            return (a, b.sub_steps(n));
+        }
+        let n = n - to_start - 1;
+        let b_span = B::MIN_VAL
+            .steps_between(&B::MAX_VAL)
+            .unwrap_or(0)
+            .saturating_add(1);
+        let borrow = (n / b_span).saturating_add(1);
+        if A::MIN_VAL.steps_between(&a).unwrap_or(0) < borrow {
+            Self::MIN_VAL
+        } else {
+            (a.sub_steps(borrow), B::MAX_VAL.sub_steps(n % b_span))
+        }
+    }
+}
+
+impl Bounded for Ipv4Addr {
+    const MIN_VAL: Ipv4Addr = Ipv4Addr::UNSPECIFIED;
+    const MAX_VAL: Ipv4Addr = Ipv4Addr::BROADCAST;
+}
+
+impl Stepped for Ipv4Addr {
+    const STEP: Ipv4Addr = Ipv4Addr::new(0, 0, 0, 1);
+    fn increment(&self) -> Self {
+        Ipv4Addr::from_bits(self.to_bits().saturating_add(1))
+    }
+    fn decrement(&self) -> Self {
+        Ipv4Addr::from_bits(self.to_bits().saturating_sub(1))
+    }
+    fn steps_between(&self, other: &Self) -> Option<u128> {
+        self.to_bits().steps_between(&other.to_bits())
+    }
+    fn add_steps(&self, n: u128) -> Self {
+        Ipv4Addr::from_bits(self.to_bits().add_steps(n))
+    }
+    fn sub_steps(&self, n: u128) -> Self {
+        Ipv4Addr::from_bits(self.to_bits().sub_steps(n))
+    }
+}
+
+impl Bounded for Ipv6Addr {
+    const MIN_VAL: Ipv6Addr = Ipv6Addr::UNSPECIFIED;
+    const MAX_VAL: Ipv6Addr = Ipv6Addr::from_bits(u128::MAX);
+}
+
+impl Stepped for Ipv6Addr {
+    const STEP: Ipv6Addr = Ipv6Addr::from_bits(1);
+    fn increment(&self) -> Self {
+        Ipv6Addr::from_bits(self.to_bits().saturating_add(1))
+    }
+    fn decrement(&self) -> Self {
+        Ipv6Addr::from_bits(self.to_bits().saturating_sub(1))
+    }
+    fn steps_between(&self, other: &Self) -> Option<u128> {
+        self.to_bits().steps_between(&other.to_bits())
+    }
+    fn add_steps(&self, n: u128) -> Self {
+        Ipv6Addr::from_bits(self.to_bits().add_steps(n))
+    }
+    fn sub_steps(&self, n: u128) -> Self {
+        Ipv6Addr::from_bits(self.to_bits().sub_steps(n))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Bounded for chrono::NaiveDate {
+    const MIN_VAL: chrono::NaiveDate = chrono::NaiveDate::MIN;
+    const MAX_VAL: chrono::NaiveDate = chrono::NaiveDate::MAX;
+}
+
+#[cfg(feature = "chrono")]
+impl Stepped for chrono::NaiveDate {
+    // a day can't be represented as a `NaiveDate`, so this is only nominal
+    const STEP: chrono::NaiveDate = chrono::NaiveDate::MIN;
+    fn increment(&self) -> Self {
+        self.succ_opt().unwrap_or(*self)
     }
     fn decrement(&self) -> Self {
-        char::from_u32(max((*self as u32).saturating_sub(1), char::MIN as u32)).unwrap()
+        self.pred_opt().unwrap_or(*self)
+    }
+    fn steps_between(&self, other: &Self) -> Option<u128> {
+        (other >= self).then(|| other.signed_duration_since(*self).num_days() as u128)
+    }
+    fn add_steps(&self, n: u128) -> Self {
+        u64::try_from(n)
+            .ok()
+            .and_then(|n| self.checked_add_days(chrono::Days::new(n)))
+            .unwrap_or(Self::MAX_VAL)
+    }
+    fn sub_steps(&self, n: u128) -> Self {
+        u64::try_from(n)
+            .ok()
+            .and_then(|n| self.checked_sub_days(chrono::Days::new(n)))
+            .unwrap_or(Self::MIN_VAL)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ranges::{DisjointRange, UnaryRange};
+    use crate::traits::{Bounded, Stepped};
+    use proptest::prelude::*;
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    fn assert_round_trip<T: Stepped + Copy + std::fmt::Debug>(v: T) {
+        assert!(v.decrement() < v);
+        assert!(v < v.increment());
+        assert_eq!(v, v.increment().decrement());
+        assert_eq!(v, v.decrement().increment());
+    }
+
+    fn assert_saturates<T: Bounded + Stepped + Copy + std::fmt::Debug>() {
+        assert_eq!(T::MAX_VAL, T::MAX_VAL.increment());
+        assert_eq!(T::MIN_VAL, T::MIN_VAL.decrement());
+        assert_eq!(T::MAX_VAL, T::MAX_VAL.decrement().increment());
+        assert_eq!(T::MIN_VAL, T::MIN_VAL.increment().decrement());
+    }
+
+    // agrees with stepping one at a time, including when it saturates
+    fn assert_add_steps<T: Stepped + Copy + std::fmt::Debug>(v: T) {
+        let (mut up, mut down) = (v, v);
+        for n in 0..5 {
+            assert_eq!(up, v.add_steps(n));
+            assert_eq!(down, v.sub_steps(n));
+            up = up.increment();
+            down = down.decrement();
+        }
+    }
+
+    macro_rules! round_trip_tests {
+        ($($name:ident: $t:ty),* $(,)?) => {
+            $(
+                proptest! {
+                    #[test]
+                    fn $name(v in (<$t>::MIN + 1)..<$t>::MAX) {
+                        assert_round_trip(v);
+                    }
+                }
+            )*
+
+            #[test]
+            fn test_integers_saturate() {
+                $(assert_saturates::<$t>();)*
+            }
+
+            #[test]
+            fn test_integers_add_steps() {
+                $(
+                    assert_add_steps::<$t>(<$t>::MIN_VAL);
+                    assert_add_steps::<$t>(<$t>::MAX_VAL);
+                    assert_eq!(<$t>::MAX_VAL, <$t>::MIN_VAL.add_steps(u128::MAX));
+                    assert_eq!(<$t>::MIN_VAL, <$t>::MAX_VAL.sub_steps(u128::MAX));
+                )*
+            }
+        };
+    }
+
+    round_trip_tests!(
+        test_u8_round_trip: u8,
+        test_u16_round_trip: u16,
+        test_u32_round_trip: u32,
+        test_u64_round_trip: u64,
+        test_u128_round_trip: u128,
+        test_usize_round_trip: usize,
+        test_i8_round_trip: i8,
+        test_i16_round_trip: i16,
+        test_i32_round_trip: i32,
+        test_i64_round_trip: i64,
+        test_i128_round_trip: i128,
+        test_isize_round_trip: isize,
+    );
+
+    proptest! {
+        #[test]
+        fn test_char_round_trip(v in any::<char>().prop_filter("not a bound", |c| {
+            *c != char::MIN_VAL && *c != char::MAX_VAL
+        })) {
+            assert_round_trip(v);
+        }
+
+        #[test]
+        fn test_char_add_steps(v in any::<char>()) {
+            assert_add_steps(v);
+        }
+    }
+
+    #[test]
+    fn test_char_steps_between() {
+        assert_eq!(Some(1), '\u{D7FF}'.steps_between(&'\u{E000}'));
+        assert_eq!(Some(0), '\u{E000}'.steps_between(&'\u{E000}'));
+        assert_eq!(Some(25), 'a'.steps_between(&'z'));
+        assert_eq!(
+            Some(0x10FFFF - 0x800),
+            char::MIN_VAL.steps_between(&char::MAX_VAL)
+        );
+        assert_eq!(None, 'z'.steps_between(&'a'));
+    }
+
+    #[test]
+    fn test_char_surrogates() {
+        assert_saturates::<char>();
+        assert_eq!('\u{E000}', '\u{D7FF}'.increment());
+        assert_eq!('\u{D7FF}', '\u{E000}'.decrement());
+        assert_round_trip('\u{D7FF}');
+        assert_round_trip('\u{E000}');
+        assert_eq!('\u{E001}', '\u{D7FE}'.add_steps(3));
+        assert_eq!('\u{D7FE}', '\u{E001}'.sub_steps(3));
+        assert_eq!(char::MAX, 'a'.add_steps(u128::MAX));
+        assert_eq!(char::MIN, 'a'.sub_steps(u128::MAX));
+    }
+
+    #[test]
+    fn test_tuple_carry_and_borrow() {
+        assert_saturates::<(u8, u8)>();
+        assert_eq!((0u8, 1u8), (0u8, 0u8).increment());
+        assert_eq!((1u8, 0u8), (0u8, u8::MAX).increment());
+        assert_eq!((0u8, u8::MAX), (1u8, 0u8).decrement());
+        assert_eq!((-1i8, char::MIN), (-2i8, char::MAX).increment());
+        assert_round_trip((3u8, u8::MAX));
+        assert_round_trip((3u8, 0u8));
+    }
+
+    proptest! {
+        #[test]
+        fn test_tuple_round_trip(a in 0u8..=3, b in any::<u8>()) {
+            let v = (a, b);
+            if v != <(u8, u8)>::MIN_VAL && v != <(u8, u8)>::MAX_VAL {
+                assert_round_trip(v);
+            }
+        }
+
+        #[test]
+        fn test_tuple_steps_between(a1 in 0u8..=3, b1 in any::<u8>(), a2 in 0u8..=3, b2 in any::<u8>()) {
+            let (from, to) = ((a1, b1), (a2, b2));
+            // both are just `u16`s in disguise
+            let flat = |(a, b): (u8, u8)| u16::from(a) << 8 | u16::from(b);
+            prop_assert_eq!(flat(from).steps_between(&flat(to)), from.steps_between(&to));
+        }
+
+        #[test]
+        fn test_tuple_add_steps(a in 0u8..=3, b in any::<u8>(), n in 0u128..70000) {
+            let v = (a, b);
+            let flat = |(a, b): (u8, u8)| u16::from(a) << 8 | u16::from(b);
+            prop_assert_eq!(flat(v).add_steps(n), flat(v.add_steps(n)));
+            prop_assert_eq!(flat(v).sub_steps(n), flat(v.sub_steps(n)));
+            assert_add_steps(v);
+        }
+    }
+
+    #[test]
+    fn test_tuple_ranges() {
+        let mut pages = DisjointRange::new_single_range_unchecked((0u8, 0u16), (0, u16::MAX));
+        pages.add_unary_range(UnaryRange::new_unchecked((1, 0), (1, 99)));
+        assert_eq!(1, pages.num_ranges());
+        assert_eq!(65536 + 100, pages.count());
+        assert!(pages.contains(&(1, 50)));
+        assert!(!pages.contains(&(1, 100)));
+        assert_eq!(
+            Some(u16::MAX as u128 + 1),
+            (0u8, 0u16).steps_between(&(1, 0))
+        );
+    }
+
+    #[test]
+    fn test_float_infinities() {
+        assert_eq!(f32::MIN, f32::NEG_INFINITY.increment());
+        assert_eq!(f32::MAX, f32::INFINITY.decrement());
+        assert_eq!(f64::MIN, f64::NEG_INFINITY.increment());
+        assert_eq!(f64::MAX, f64::INFINITY.decrement());
+        assert_eq!(f64::INFINITY, f64::INFINITY.increment());
+        assert_eq!(f64::NEG_INFINITY, f64::NEG_INFINITY.decrement());
+        assert_eq!(1.0 + f64::EPSILON, 1.0f64.increment());
+        assert_eq!(f64::MIN, f64::NEG_INFINITY.add_steps(1));
+        assert_eq!(f32::MAX, f32::INFINITY.sub_steps(1));
+        assert_eq!(f64::NEG_INFINITY, f64::NEG_INFINITY.add_steps(0));
+        assert_eq!(1.0 + 4.0 * f64::EPSILON, 1.0f64.add_steps(4));
+    }
+
+    #[test]
+    fn test_ip_addrs() {
+        assert_saturates::<Ipv4Addr>();
+        assert_saturates::<Ipv6Addr>();
+        assert_eq!(
+            Ipv4Addr::new(10, 0, 1, 0),
+            Ipv4Addr::new(10, 0, 0, 255).increment()
+        );
+        assert_eq!(
+            "2001:db8::1:0".parse::<Ipv6Addr>().unwrap(),
+            "2001:db8::ffff".parse::<Ipv6Addr>().unwrap().increment()
+        );
+        assert_eq!(
+            Some(255),
+            Ipv4Addr::new(10, 0, 0, 0).steps_between(&Ipv4Addr::new(10, 0, 0, 255))
+        );
+        assert_eq!(
+            Ipv4Addr::new(10, 0, 1, 4),
+            Ipv4Addr::new(10, 0, 0, 0).add_steps(260)
+        );
+        assert_eq!(
+            Ipv4Addr::BROADCAST,
+            Ipv4Addr::new(10, 0, 0, 0).add_steps(1 << 40)
+        );
+        assert_eq!(Ipv6Addr::UNSPECIFIED, Ipv6Addr::LOCALHOST.sub_steps(2));
+    }
+
+    #[test]
+    fn test_ipv6_counts() {
+        let entire = DisjointRange::<Ipv6Addr>::entire();
+        assert_eq!(None, entire.checked_count());
+        assert_eq!(u128::MAX, entire.count());
+        let subnet = UnaryRange::new_unchecked(
+            "2001:db8::".parse::<Ipv6Addr>().unwrap(),
+            "2001:db8::ffff".parse::<Ipv6Addr>().unwrap(),
+        );
+        assert_eq!(Some(0x10000), subnet.checked_count());
+    }
+}
+
+#[cfg(all(test, feature = "chrono"))]
+mod chrono_tests {
+    use crate::ranges::{DisjointRange, UnaryRange};
+    use crate::traits::Stepped;
+    use chrono::NaiveDate;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_naive_date_stepped() {
+        assert_eq!(date(2024, 3, 1), date(2024, 2, 29).increment());
+        assert_eq!(date(2023, 12, 31), date(2024, 1, 1).decrement());
+        assert_eq!(NaiveDate::MAX, NaiveDate::MAX.increment());
+        assert_eq!(NaiveDate::MIN, NaiveDate::MIN.decrement());
+        assert_eq!(Some(366), date(2024, 1, 1).steps_between(&date(2025, 1, 1)));
+        assert_eq!(date(2025, 1, 1), date(2024, 1, 1).add_steps(366));
+        assert_eq!(date(2024, 1, 1), date(2025, 1, 1).sub_steps(366));
+        assert_eq!(NaiveDate::MAX, date(2024, 1, 1).add_steps(u128::MAX));
+        assert_eq!(NaiveDate::MIN, date(2024, 1, 1).sub_steps(1 << 40));
+    }
+
+    #[test]
+    fn test_naive_date_availability() {
+        let mut available =
+            DisjointRange::new_single_range_unchecked(date(2024, 6, 1), date(2024, 6, 30));
+        available.subtract_unary_range(UnaryRange::new_unchecked(
+            date(2024, 6, 10),
+            date(2024, 6, 12),
+        ));
+        assert!(available.contains(&date(2024, 6, 9)));
+        assert!(!available.contains(&date(2024, 6, 10)));
+        assert!(!available.contains(&date(2024, 6, 12)));
+        assert!(available.contains(&date(2024, 6, 13)));
     }
 }
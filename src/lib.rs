@@ -1,8 +1,19 @@
 //! Unary and Disjoint ranges plus some useful traits
 
+pub mod builder;
+#[cfg(feature = "serde")]
+pub mod compact;
+pub mod continuous;
+pub mod error;
 pub mod impls;
+pub mod newtypes;
 pub mod ranges;
 pub mod traits;
 
-pub use ranges::{DisjointRange, UnaryRange};
+pub use builder::DisjointRangeBuilder;
+#[cfg(feature = "serde")]
+pub use compact::CompactRange;
+pub use continuous::ContinuousRange;
+pub use error::RangeError;
+pub use ranges::{DisjointRange, Membership, StepMode, UnaryRange};
 pub use traits::{Bounded, Stepped};
@@ -0,0 +1,512 @@
+//! Newtypes with useful [Bounded] and [Stepped] implementations
+
+use crate::traits::{Bounded, Stepped};
+use std::cmp::{Ordering, min};
+use std::fmt;
+use std::time::Duration;
+
+/// A `u64` that [steps](Stepped) by `S` instead of `1`
+///
+/// This is [Bounded] by `0` and the largest multiple of `S` that fits in a `u64`, so e.g.
+/// a `UnaryRange<StrideU64<4096>>` covers a series of 4KiB pages, and melding and
+/// complements work in terms of whole pages:
+///
+/// ```
+/// use disjoint_ranges::{DisjointRange, UnaryRange};
+/// use disjoint_ranges::newtypes::StrideU64;
+///
+/// let mut pages = DisjointRange::new_single_range_unchecked(StrideU64::<4096>(0), StrideU64(4096));
+/// pages.add_unary_range(UnaryRange::new_unchecked(StrideU64(8192), StrideU64(12288)));
+/// assert_eq!(1, pages.ranges_iter().count());
+/// ```
+///
+/// Values are expected to be multiples of `S`. Unaligned values aren't rounded and still step
+/// by `S`, so a range from `100` to `8292` contains `100`, `4196` and `8292`. Mixing aligned and
+/// unaligned values in the same [`DisjointRange`](crate::ranges::DisjointRange) makes melding
+/// inconsistent, so stick to aligned values (or values that all share the same offset).
+///
+/// `S` must be greater than `0`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StrideU64<const S: u64>(pub u64);
+
+impl<const S: u64> Bounded for StrideU64<S> {
+    const MIN_VAL: Self = StrideU64(0);
+    const MAX_VAL: Self = StrideU64(u64::MAX - u64::MAX % S);
+}
+
+impl<const S: u64> Stepped for StrideU64<S> {
+    const STEP: Self = StrideU64(S);
+    fn increment(&self) -> Self {
+        StrideU64(min(self.0.saturating_add(S), Self::MAX_VAL.0))
+    }
+    fn decrement(&self) -> Self {
+        StrideU64(self.0.saturating_sub(S))
+    }
+    fn steps_between(&self, other: &Self) -> Option<u128> {
+        (other >= self).then(|| ((other.0 - self.0) / S) as u128)
+    }
+    fn add_steps(&self, n: u128) -> Self {
+        let delta = u64::try_from(n).map_or(u64::MAX, |n| n.saturating_mul(S));
+        StrideU64(min(self.0.saturating_add(delta), Self::MAX_VAL.0))
+    }
+    fn sub_steps(&self, n: u128) -> Self {
+        let delta = u64::try_from(n).map_or(u64::MAX, |n| n.saturating_mul(S));
+        StrideU64(self.0.saturating_sub(delta))
+    }
+}
+
+/// A distinct wrapper around another [Bounded] and [Stepped] type, e.g. byte offsets that
+/// are really `u64`s
+///
+/// [Bounded] and [Stepped] are forwarded to the inner `T`, so an `Offset<u64>` behaves
+/// exactly like a `u64`, but a `UnaryRange<Offset<u64>>` can't be accidentally combined with
+/// a `UnaryRange<u64>` of something else entirely:
+///
+/// ```
+/// use disjoint_ranges::{DisjointRange, UnaryRange};
+/// use disjoint_ranges::newtypes::Offset;
+///
+/// let mut written = DisjointRange::new_single_range_unchecked(Offset(0u64), Offset(511));
+/// written.add_unary_range(UnaryRange::new_unchecked(Offset(512), Offset(1023)));
+/// assert_eq!(1024, written.count());
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Offset<T>(pub T);
+
+impl<T: Bounded> Bounded for Offset<T> {
+    const MIN_VAL: Self = Offset(T::MIN_VAL);
+    const MAX_VAL: Self = Offset(T::MAX_VAL);
+}
+
+impl<T: Stepped + Copy> Stepped for Offset<T> {
+    const STEP: Self = Offset(T::STEP);
+    fn increment(&self) -> Self {
+        Offset(self.0.increment())
+    }
+    fn decrement(&self) -> Self {
+        Offset(self.0.decrement())
+    }
+    fn steps_between(&self, other: &Self) -> Option<u128> {
+        self.0.steps_between(&other.0)
+    }
+    fn add_steps(&self, n: u128) -> Self {
+        Offset(self.0.add_steps(n))
+    }
+    fn sub_steps(&self, n: u128) -> Self {
+        Offset(self.0.sub_steps(n))
+    }
+}
+
+/// A [`Duration`] that [steps](Stepped) by one millisecond
+///
+/// This is [Bounded] by [`Duration::ZERO`] and [`Duration::MAX`] truncated to a whole
+/// millisecond, so a `DisjointRange<SteppedMillis>` models time windows at millisecond
+/// granularity, and e.g. [`DisjointRange::count`](crate::ranges::DisjointRange::count)
+/// counts milliseconds:
+///
+/// ```
+/// use std::time::Duration;
+/// use disjoint_ranges::DisjointRange;
+/// use disjoint_ranges::newtypes::SteppedMillis;
+///
+/// let window = DisjointRange::new_single_range_unchecked(
+///     SteppedMillis(Duration::from_secs(1)),
+///     SteppedMillis(Duration::from_secs(2)),
+/// );
+/// assert_eq!(1001, window.count());
+/// ```
+///
+/// Values are expected to be whole milliseconds. Sub-millisecond components are
+/// truncated away when stepping, which moves to the next or previous whole millisecond,
+/// so `1.5ms` increments to `2ms` and decrements to `1ms`.
+/// [`Stepped::steps_between`] ignores them too.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SteppedMillis(pub Duration);
+
+impl SteppedMillis {
+    fn from_millis(millis: u128) -> Self {
+        SteppedMillis(Duration::new(
+            (millis / 1000) as u64,
+            ((millis % 1000) * 1_000_000) as u32,
+        ))
+    }
+}
+
+impl Bounded for SteppedMillis {
+    const MIN_VAL: Self = SteppedMillis(Duration::ZERO);
+    const MAX_VAL: Self = SteppedMillis(Duration::new(u64::MAX, 999_000_000));
+}
+
+impl Stepped for SteppedMillis {
+    const STEP: Self = SteppedMillis(Duration::from_millis(1));
+    fn increment(&self) -> Self {
+        if *self >= Self::MAX_VAL {
+            Self::MAX_VAL
+        } else {
+            Self::from_millis(self.0.as_millis() + 1)
+        }
+    }
+    fn decrement(&self) -> Self {
+        let millis = self.0.as_millis();
+        if !self.0.subsec_nanos().is_multiple_of(1_000_000) {
+            Self::from_millis(millis)
+        } else {
+            Self::from_millis(millis.saturating_sub(1))
+        }
+    }
+    fn steps_between(&self, other: &Self) -> Option<u128> {
+        (other >= self).then(|| other.0.as_millis() - self.0.as_millis())
+    }
+    fn add_steps(&self, n: u128) -> Self {
+        if n == 0 {
+            return *self;
+        }
+        match self.0.as_millis().checked_add(n) {
+            Some(millis) if millis <= Self::MAX_VAL.0.as_millis() => Self::from_millis(millis),
+            _ => Self::MAX_VAL,
+        }
+    }
+    fn sub_steps(&self, n: u128) -> Self {
+        if n == 0 {
+            return *self;
+        }
+        let mut millis = self.0.as_millis();
+        if !self.0.subsec_nanos().is_multiple_of(1_000_000) {
+            // the first step only truncates
+            millis += 1;
+        }
+        Self::from_millis(millis.saturating_sub(n))
+    }
+}
+
+/// A wrapper around another [Bounded] and [Stepped] type that runs in the opposite
+/// direction, e.g. for countdowns
+///
+/// [Ord] is flipped, [`Bounded::MIN_VAL`] and [`Bounded::MAX_VAL`] are swapped, and so are
+/// [`Stepped::increment`] and [`Stepped::decrement`], so a `DisjointRange<Rev<u32>>` keeps
+/// its ranges (and values) in descending order of the inner `u32`s:
+///
+/// ```
+/// use disjoint_ranges::{DisjointRange, UnaryRange};
+/// use disjoint_ranges::newtypes::Rev;
+///
+/// let countdown = DisjointRange::from_ranges(vec![
+///     UnaryRange::new_unchecked(Rev(3u32), Rev(1)),
+///     UnaryRange::new_unchecked(Rev(10), Rev(8)),
+/// ]);
+/// let values: Vec<u32> = countdown.iter_values_bounded(10).map(|v| v.0).collect();
+/// assert_eq!(vec![10, 9, 8, 3, 2, 1], values);
+/// ```
+///
+/// Note that ranges go from high to low inner values, e.g. `Rev(3)` to `Rev(1)`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Rev<T>(pub T);
+
+impl<T: PartialOrd> PartialOrd for Rev<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        other.0.partial_cmp(&self.0)
+    }
+}
+
+impl<T: Ord> Ord for Rev<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.cmp(&self.0)
+    }
+}
+
+impl<T: Bounded> Bounded for Rev<T> {
+    const MIN_VAL: Self = Rev(T::MAX_VAL);
+    const MAX_VAL: Self = Rev(T::MIN_VAL);
+}
+
+impl<T: Stepped + Copy> Stepped for Rev<T> {
+    const STEP: Self = Rev(T::STEP);
+    fn increment(&self) -> Self {
+        Rev(self.0.decrement())
+    }
+    fn decrement(&self) -> Self {
+        Rev(self.0.increment())
+    }
+    fn checked_increment(&self) -> Option<Self> {
+        self.0.checked_decrement().map(Rev)
+    }
+    fn checked_decrement(&self) -> Option<Self> {
+        self.0.checked_increment().map(Rev)
+    }
+    fn steps_between(&self, other: &Self) -> Option<u128> {
+        other.0.steps_between(&self.0)
+    }
+    fn add_steps(&self, n: u128) -> Self {
+        Rev(self.0.sub_steps(n))
+    }
+    fn sub_steps(&self, n: u128) -> Self {
+        Rev(self.0.add_steps(n))
+    }
+}
+
+/// An ASCII byte, i.e. a `u8` restricted to `0..=127`
+///
+/// This is [Bounded] by `0` and `127` and [Stepped] by `1` like a `u8`, and displays
+/// printable characters as themselves and everything else as `\xNN`, so a
+/// `DisjointRange<Ascii>` works as a readable character class. The separators `-` and
+/// `,` and the escape character `\` are shown as `\xNN` too, so the output is never
+/// ambiguous:
+///
+/// ```
+/// use disjoint_ranges::DisjointRange;
+/// use disjoint_ranges::newtypes::Ascii;
+///
+/// let word = DisjointRange::from_bounds_unchecked([
+///     (Ascii(b'a'), Ascii(b'z')),
+///     (Ascii(b'0'), Ascii(b'9')),
+///     (Ascii(b'A'), Ascii(b'Z')),
+/// ]);
+/// assert_eq!("0-9,A-Z,a-z", word.to_string());
+/// assert_eq!("\\x00-/,:-@,[-`,{-\\x7f", word.complement().to_string());
+/// ```
+///
+/// Values above `127` are expected not to be used.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ascii(pub u8);
+
+impl Bounded for Ascii {
+    const MIN_VAL: Self = Ascii(0);
+    const MAX_VAL: Self = Ascii(0x7F);
+}
+
+impl Stepped for Ascii {
+    const STEP: Self = Ascii(1);
+    fn increment(&self) -> Self {
+        Ascii(min(self.0.saturating_add(1), Self::MAX_VAL.0))
+    }
+    fn decrement(&self) -> Self {
+        Ascii(self.0.saturating_sub(1))
+    }
+    fn steps_between(&self, other: &Self) -> Option<u128> {
+        self.0.steps_between(&other.0)
+    }
+    fn add_steps(&self, n: u128) -> Self {
+        Ascii(min(self.0.add_steps(n), Self::MAX_VAL.0))
+    }
+    fn sub_steps(&self, n: u128) -> Self {
+        Ascii(self.0.sub_steps(n))
+    }
+}
+
+impl fmt::Display for Ascii {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let special = matches!(self.0, b'-' | b',' | b'\\');
+        if (self.0.is_ascii_graphic() || self.0 == b' ') && !special {
+            write!(f, "{}", self.0 as char)
+        } else {
+            write!(f, "\\x{:02x}", self.0)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Ascii, Offset, Rev, SteppedMillis, StrideU64};
+    use crate::ranges::{DisjointRange, UnaryRange};
+    use crate::traits::{Bounded, Stepped};
+    use std::time::Duration;
+
+    type Page = StrideU64<4096>;
+
+    #[test]
+    fn test_stride_stepped() {
+        assert_eq!(StrideU64(8192), Page::STEP.increment());
+        assert_eq!(Page::MIN_VAL, Page::STEP.decrement());
+        assert_eq!(Page::MIN_VAL, Page::MIN_VAL.decrement());
+        assert_eq!(Page::MAX_VAL, Page::MAX_VAL.increment());
+        assert_eq!(0, Page::MAX_VAL.0 % 4096);
+        assert_eq!(
+            Some(3),
+            StrideU64::<4096>(4096).steps_between(&StrideU64(16384))
+        );
+        assert_eq!(StrideU64(16384), Page::STEP.add_steps(3));
+        assert_eq!(Page::STEP, StrideU64(16384).sub_steps(3));
+        assert_eq!(Page::MAX_VAL, Page::STEP.add_steps(u128::MAX));
+    }
+
+    #[test]
+    fn test_stride_values() {
+        let values: Vec<u64> = UnaryRange::new_unchecked(StrideU64::<4096>(0), StrideU64(12288))
+            .into_iter()
+            .map(|page| page.0)
+            .collect();
+        assert_eq!(vec![0, 4096, 8192, 12288], values);
+    }
+
+    #[test]
+    fn test_stride_complement() {
+        let pages =
+            DisjointRange::new_single_range_unchecked(StrideU64::<4096>(4096), StrideU64(8192));
+        let complement: Vec<(u64, u64)> = pages
+            .complement()
+            .ranges_iter()
+            .map(|range| {
+                let (low, high) = range.as_bounds();
+                (low.0, high.0)
+            })
+            .collect();
+        assert_eq!(vec![(0, 0), (12288, Page::MAX_VAL.0)], complement);
+    }
+
+    #[test]
+    fn test_offset_forwards() {
+        assert_eq!(Offset(u64::MIN), Offset::<u64>::MIN_VAL);
+        assert_eq!(Offset(u64::MAX), Offset::<u64>::MAX_VAL);
+        assert_eq!(Offset(11u64), Offset(10u64).increment());
+        assert_eq!(Offset(9u64), Offset(10u64).decrement());
+        assert_eq!(Offset::<u64>::MAX_VAL, Offset::<u64>::MAX_VAL.increment());
+        assert_eq!(
+            Some(u64::MAX as u128),
+            Offset::<u64>::MIN_VAL.steps_between(&Offset::MAX_VAL)
+        );
+        assert_eq!(Offset(110u64), Offset(10u64).add_steps(100));
+        assert_eq!(Offset::<u64>::MIN_VAL, Offset(10u64).sub_steps(100));
+    }
+
+    #[test]
+    fn test_offset_ranges() {
+        let mut written = DisjointRange::from_bounds_unchecked([(Offset(0u64), Offset(99))]);
+        written.add_unary_range(UnaryRange::new_unchecked(Offset(100), Offset(199)));
+        written.subtract_unary_range(UnaryRange::new_unchecked(Offset(50), Offset(59)));
+        let ranges: Vec<(u64, u64)> = written
+            .ranges_iter()
+            .map(|range| {
+                let (low, high) = range.as_bounds();
+                (low.0, high.0)
+            })
+            .collect();
+        assert_eq!(vec![(0, 49), (60, 199)], ranges);
+        assert_eq!(190, written.count());
+    }
+
+    #[test]
+    fn test_stepped_millis() {
+        let ms = |millis| SteppedMillis(Duration::from_millis(millis));
+        assert_eq!(ms(6), ms(5).increment());
+        assert_eq!(ms(4), ms(5).decrement());
+        assert_eq!(ms(1000), ms(999).increment());
+        let partial = SteppedMillis(Duration::from_micros(1500));
+        assert_eq!(ms(2), partial.increment());
+        assert_eq!(ms(1), partial.decrement());
+        assert_eq!(Some(1), ms(0).steps_between(&partial));
+        assert_eq!(Some(0), ms(1).steps_between(&partial));
+        assert_eq!(SteppedMillis::MIN_VAL, SteppedMillis::MIN_VAL.decrement());
+        assert_eq!(SteppedMillis::MAX_VAL, SteppedMillis::MAX_VAL.increment());
+        assert_eq!(
+            SteppedMillis::MAX_VAL,
+            SteppedMillis::MAX_VAL.decrement().increment()
+        );
+        SteppedMillis::validate();
+        assert_eq!(ms(1005), ms(5).add_steps(1000));
+        assert_eq!(ms(2), partial.add_steps(1));
+        assert_eq!(ms(0), partial.sub_steps(2));
+        assert_eq!(partial, partial.sub_steps(0));
+        assert_eq!(SteppedMillis::MAX_VAL, ms(5).add_steps(u128::MAX));
+        assert_eq!(
+            SteppedMillis::MAX_VAL,
+            SteppedMillis::MAX_VAL.sub_steps(3).add_steps(3)
+        );
+    }
+
+    #[test]
+    fn test_stepped_millis_ranges() {
+        let ms = |millis| SteppedMillis(Duration::from_millis(millis));
+        let mut busy = DisjointRange::new_single_range_unchecked(ms(0), ms(999));
+        busy.add_unary_range(UnaryRange::new_unchecked(ms(1000), ms(1499)));
+        assert_eq!(1, busy.num_ranges());
+        assert_eq!(1500, busy.count());
+        let free = busy.complement_in(&DisjointRange::new_single_range_unchecked(ms(0), ms(1999)));
+        assert_eq!(free, UnaryRange::new_unchecked(ms(1500), ms(1999)));
+        let entire = DisjointRange::<SteppedMillis>::entire();
+        assert_eq!(Some(u64::MAX as u128 * 1000 + 1000), entire.checked_count());
+    }
+
+    #[test]
+    fn test_stepped_millis_values() {
+        let us = |micros| SteppedMillis(Duration::from_micros(micros));
+        let range = UnaryRange::new_unchecked(us(500), us(2500));
+        let values: Vec<SteppedMillis> = range.into_iter().collect();
+        assert_eq!(vec![us(500), us(1000), us(2000)], values);
+        assert_eq!(values.len() as u128, range.count());
+    }
+
+    #[test]
+    fn test_rev_flips() {
+        assert!(Rev(1u32) > Rev(2));
+        assert_eq!(Rev(u32::MAX), Rev::<u32>::MIN_VAL);
+        assert_eq!(Rev(0u32), Rev::<u32>::MAX_VAL);
+        assert_eq!(Rev(9u32), Rev(10u32).increment());
+        assert_eq!(Rev(11u32), Rev(10u32).decrement());
+        assert_eq!(Rev::<u32>::MAX_VAL, Rev::<u32>::MAX_VAL.increment());
+        assert_eq!(None, Rev::<u32>::MAX_VAL.checked_increment());
+        assert_eq!(Some(Rev(1u32)), Rev(0u32).checked_decrement());
+        assert_eq!(Some(7), Rev(10u32).steps_between(&Rev(3)));
+        assert_eq!(None, Rev(3u32).steps_between(&Rev(10)));
+        assert_eq!(Rev(3u32), Rev(10u32).add_steps(7));
+        assert_eq!(Rev(10u32), Rev(3u32).sub_steps(7));
+        Rev::<u32>::validate();
+    }
+
+    #[test]
+    fn test_rev_ranges() {
+        let range = |high, low| UnaryRange::new_unchecked(Rev(high), Rev(low));
+        let mut countdown = DisjointRange::from_ranges(vec![range(5u8, 3), range(10, 6)]);
+        assert_eq!(1, countdown.num_ranges());
+        assert_eq!(8, countdown.count());
+        countdown.subtract_unary_range(range(8, 8));
+        let bounds: Vec<(u8, u8)> = countdown
+            .ranges_iter()
+            .map(|range| {
+                let (low, high) = range.as_bounds();
+                (low.0, high.0)
+            })
+            .collect();
+        assert_eq!(vec![(10, 9), (7, 3)], bounds);
+        let complement: Vec<(u8, u8)> = countdown
+            .complement()
+            .ranges_iter()
+            .map(|range| {
+                let (low, high) = range.as_bounds();
+                (low.0, high.0)
+            })
+            .collect();
+        assert_eq!(vec![(u8::MAX, 11), (8, 8), (2, 0)], complement);
+    }
+
+    #[test]
+    fn test_ascii_stepped() {
+        assert_eq!(Ascii(b'b'), Ascii(b'a').increment());
+        assert_eq!(Ascii(b'`'), Ascii(b'a').decrement());
+        assert_eq!(Ascii::MAX_VAL, Ascii::MAX_VAL.increment());
+        assert_eq!(Ascii::MIN_VAL, Ascii::MIN_VAL.decrement());
+        assert_eq!(Ascii::MAX_VAL, Ascii(b'a').add_steps(1000));
+        assert_eq!(Some(25), Ascii(b'a').steps_between(&Ascii(b'z')));
+        assert_eq!(128, DisjointRange::<Ascii>::entire().count());
+        assert_eq!(None, DisjointRange::<Ascii>::entire().count_t());
+        let digits = DisjointRange::new_single_range_unchecked(Ascii(b'0'), Ascii(b'9'));
+        assert_eq!(Some(Ascii(10)), digits.count_t());
+        Ascii::validate();
+    }
+
+    #[test]
+    fn test_ascii_display() {
+        assert_eq!("A", Ascii(b'A').to_string());
+        assert_eq!(" ", Ascii(b' ').to_string());
+        assert_eq!("\\x0a", Ascii(b'\n').to_string());
+        assert_eq!("\\x7f", Ascii(0x7F).to_string());
+        let mut class = DisjointRange::new_single_range_unchecked(Ascii(b'a'), Ascii(b'z'));
+        class.subtract_unary_range(UnaryRange::new_unchecked(Ascii(b'm'), Ascii(b'm')));
+        assert_eq!("a-l,n-z", class.to_string());
+        assert_eq!("\\x2d", Ascii(b'-').to_string());
+        let punct = DisjointRange::from_bounds_unchecked([
+            (Ascii(b'+'), Ascii(b'-')),
+            (Ascii(b'\\'), Ascii(b'\\')),
+        ]);
+        assert_eq!("+-\\x2d,\\x5c", punct.to_string());
+    }
+}
@@ -38,10 +38,30 @@
 //!   [`DisjointRange::new_single_range`] and [`DisjointRange::from_bounds`]
 //!   return `None` if this condition doesn't hold. There are corresponding
 //!   `_unchecked` methods if you're willing to fly without a net.
+//!
+//!   Most operations only need `T: Stepped`, so they work for types without fixed
+//!   bounds too (e.g. arbitrary-precision integers). Those that need the whole
+//!   domain, like [`DisjointRange::entire`] and the complements, also require
+//!   [`Bounded`], and in debug builds they [validate](Stepped::validate) `T`'s
+//!   [`Stepped`] impl against its bounds. The checked constructors above don't, so
+//!   they work for unbounded types as well; [`UnaryRange::new_validated`] is the
+//!   checked constructor that validates.
+//!
+//!   Construction, [`contains`](DisjointRange::contains),
+//!   [`intersect`](UnaryRange::intersect), [`without`](UnaryRange::without) and melding
+//!   only need `T: Clone`, so they work for non-`Copy` types (e.g. `num_bigint::BigInt`)
+//!   too. The rest of the API still requires `T: Copy`.
 
 use std::cmp::{max, min};
-use std::collections::VecDeque;
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt;
+use std::iter::Peekable;
+use std::net::Ipv4Addr;
+use std::ops::{Add, Bound, Index, RangeBounds, RangeInclusive, Rem, Sub};
+use std::slice::Windows;
+use std::str::FromStr;
 
+use crate::error::RangeError;
 use crate::traits::{Bounded, Stepped, bounded_max, bounded_min};
 
 /// A single contiguous range of values
@@ -56,11 +76,24 @@ pub struct UnaryRange<T> {
     high: T,
 }
 
+/// Construction and comparisons only, so these work for any `T: Clone + PartialOrd`, e.g. `f64`s
+/// or arbitrary-precision integers
 impl<T> UnaryRange<T>
 where
-    T: Copy + Clone + Bounded + Stepped,
+    T: Clone + PartialOrd,
 {
     /// Create a new [`UnaryRange`] from `low` and `high` values
+    ///
+    /// `low > high` will result in undesired behavior
+    ///
+    /// This is a `const fn`, so it can be used to build ranges in `const`s and `static`s
+    pub const fn new_unchecked(low: T, high: T) -> Self {
+        Self { low, high }
+    }
+
+    /// Create a new [`UnaryRange`] from `low` and `high` values
+    ///
+    /// Returns `None` unless `low <= high`, which also rules out `NaN`s
     pub fn new(low: T, high: T) -> Option<Self> {
         if low <= high {
             Some(Self { low, high })
@@ -69,11 +102,11 @@ where
         }
     }
 
-    /// Create a new [`UnaryRange`] from `low` and `high` values
+    /// Create a new [`UnaryRange`] from a `(low, high)` tuple
     ///
-    /// `low > high` will result in undesired behavior
-    pub fn new_unchecked(low: T, high: T) -> Self {
-        Self { low, high }
+    /// See [`UnaryRange::new`]
+    pub fn from_tuple((low, high): (T, T)) -> Option<Self> {
+        Self::new(low, high)
     }
 
     /// Test whether a value is contained within the range
@@ -81,11 +114,115 @@ where
         *val >= self.low && *val <= self.high
     }
 
+    /// Test whether `other` fits entirely within the range
+    pub fn contains_range(&self, other: &Self) -> bool {
+        self.low <= other.low && other.high <= self.high
+    }
+
     /// Get `(low, high)`
     pub fn as_bounds(&self) -> (T, T) {
-        (self.low, self.high)
+        (self.low.clone(), self.high.clone())
+    }
+
+    /// Get `(low, high)`
+    ///
+    /// This is the same as [`UnaryRange::as_bounds`], and the inverse of [`UnaryRange::from_tuple`]
+    pub fn as_tuple(&self) -> (T, T) {
+        self.as_bounds()
+    }
+
+    /// Test whether the range shares any values with `other`
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.low <= other.high && other.low <= self.high
+    }
+
+    /// The values shared by the range and `other`, or `None` if they don't overlap
+    pub fn intersect(&self, other: &Self) -> Option<Self> {
+        if !self.overlaps(other) {
+            return None;
+        }
+        let low = if other.low > self.low {
+            &other.low
+        } else {
+            &self.low
+        };
+        let high = if other.high < self.high {
+            &other.high
+        } else {
+            &self.high
+        };
+        Some(Self::new_unchecked(low.clone(), high.clone()))
+    }
+}
+
+impl<T> UnaryRange<T>
+where
+    T: Copy + Clone + Stepped,
+{
+    /// Create a new [`UnaryRange`] from a half-open `[low, high_exclusive)`
+    ///
+    /// The range is converted to the usual inclusive form by
+    /// [decrementing](Stepped::decrement) `high_exclusive`, so e.g. `new_exclusive(0, 10)`
+    /// covers `0` to `9`. Returns `None` if the half-open range is empty, i.e. unless
+    /// `low < high_exclusive`.
+    pub fn new_exclusive(low: T, high_exclusive: T) -> Option<Self> {
+        (low < high_exclusive).then(|| Self::new_unchecked(low, high_exclusive.decrement()))
+    }
+
+    /// Iterator over every `stride`-th value, starting at `low` and stopping at or
+    /// before `high`
+    ///
+    /// Each step [adds](Stepped::add_steps) `stride` steps at once, stopping if that
+    /// would go past `high`, so this never saturates at [`Bounded::MAX_VAL`]. Like
+    /// [`Iterator::step_by`], this panics if `stride` is `0`.
+    pub fn step_by(self, stride: usize) -> impl Iterator<Item = T> {
+        assert!(stride > 0, "stride must be positive");
+        let stride = stride as u128;
+        std::iter::successors(Some(self.low), move |val| {
+            let remaining = val.steps_between(&self.high)?;
+            (remaining >= stride).then(|| val.add_steps(stride))
+        })
+    }
+
+    /// The number of values shared by this range and `other`
+    ///
+    /// This is `0` if the two ranges don't overlap, and saturates at `u128::MAX`.
+    pub fn overlap_len(&self, other: &Self) -> u128 {
+        let low = if other.low > self.low {
+            other.low
+        } else {
+            self.low
+        };
+        let high = if other.high < self.high {
+            other.high
+        } else {
+            self.high
+        };
+        Self::new_unchecked(low, high).count()
+    }
+
+    /// The number of values in the range
+    ///
+    /// This saturates at `u128::MAX`, which is one less than the number of values in
+    /// e.g. a `UnaryRange<u128>` covering every `u128`. See [`UnaryRange::checked_count`].
+    pub fn count(&self) -> u128 {
+        self.low
+            .steps_between(&self.high)
+            .map_or(0, |steps| steps.saturating_add(1))
+    }
+
+    /// The number of values in the range, or `None` if that's more than `u128::MAX`
+    pub fn checked_count(&self) -> Option<u128> {
+        self.low
+            .steps_between(&self.high)
+            .map_or(Some(0), |steps| steps.checked_add(1))
     }
+}
 
+impl<T> UnaryRange<T>
+where
+    T: Clone + Stepped,
+{
     /// The current range without `other`
     ///
     /// This is like subtraction, but returns `Option<Vec<Self>>`.
@@ -162,34 +299,84 @@ where
     ///       low)   high)
     /// ```
     pub fn without(self, other: Self) -> Option<Vec<Self>> {
+        self.without_ref(&other)
+    }
+
+    /// The current range without `other`, without consuming either
+    ///
+    /// See [`UnaryRange::without`]
+    pub fn without_ref(&self, other: &Self) -> Option<Vec<Self>> {
         if other.low > self.high || other.high < self.low {
-            Some(vec![self])
+            Some(vec![self.clone()])
+        } else if other.low <= self.low && other.high >= self.high {
+            None
         } else if other.high >= self.high {
-            if other.low.decrement() > self.low {
-                Some(vec![Self::new_unchecked(self.low, other.low.decrement())])
-            } else {
-                None
-            }
+            Some(vec![Self::new_unchecked(
+                self.low.clone(),
+                other.low.decrement(),
+            )])
         } else if other.low <= self.low {
-            if other.high.increment() < self.high {
-                Some(vec![Self::new_unchecked(other.high.increment(), self.high)])
-            } else {
-                None
-            }
+            Some(vec![Self::new_unchecked(
+                other.high.increment(),
+                self.high.clone(),
+            )])
         } else {
             Some(vec![
-                UnaryRange::new_unchecked(self.low, other.low.decrement()),
-                UnaryRange::new_unchecked(other.high.increment(), self.high),
+                UnaryRange::new_unchecked(self.low.clone(), other.low.decrement()),
+                UnaryRange::new_unchecked(other.high.increment(), self.high.clone()),
             ])
         }
     }
 }
 
+impl<T> UnaryRange<T>
+where
+    T: Ord + Copy + Clone + Stepped,
+{
+    /// Combine this range with `other` if they overlap or are adjacent
+    ///
+    /// Like [`DisjointRange::meld_ranges`], ranges one [step](Stepped::STEP) apart count as
+    /// adjacent, e.g. `1..=4` and `5..=8` become `1..=8`. Otherwise both ranges are returned,
+    /// lower one first.
+    pub fn union(self, other: Self) -> Result<Self, (Self, Self)> {
+        let (lower, upper) = if self.low <= other.low {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        let touching = lower
+            .high
+            .checked_increment()
+            .is_none_or(|after| upper.low <= after);
+        if touching {
+            Ok(Self::new_unchecked(lower.low, max(lower.high, upper.high)))
+        } else {
+            Err((lower, upper))
+        }
+    }
+}
+
+impl<T> UnaryRange<T>
+where
+    T: Copy + Clone + Bounded + Stepped,
+{
+    /// Create a new [`UnaryRange`] like [`UnaryRange::new`], also
+    /// [validating](Stepped::validate) `T`'s [`Stepped`] impl
+    ///
+    /// The validation only happens in debug builds, so this is as cheap as
+    /// [`UnaryRange::new`] in release builds.
+    pub fn new_validated(low: T, high: T) -> Option<Self> {
+        T::validate();
+        Self::new(low, high)
+    }
+}
+
 impl<T> UnaryRange<T>
 where
     T: Ord + Copy + Clone + Bounded + Stepped,
 {
     fn complement_ranges(self) -> Vec<UnaryRange<T>> {
+        T::validate();
         if self.low == bounded_min() && self.high == bounded_max() {
             Vec::default()
         } else if self.low == bounded_min() {
@@ -227,448 +414,4715 @@ where
     /// N.B.: it'll return a 2-range vector unless `self.low == bounded_min()` or
     /// `self.high == bounded_max()`.
     pub fn complement(self) -> Option<DisjointRange<T>> {
-        let s = self.clone();
-        let mut res = DisjointRange::from_ranges(self.complement_ranges());
-        res.subtract_unary_range(s);
-        if res.ranges.len() > 0 {
-            Some(res)
+        let ranges = self.complement_ranges();
+        if !ranges.is_empty() {
+            Some(DisjointRange::from_ranges(ranges))
         } else {
             None
         }
     }
-}
 
-/// A range with gaps
-///
-/// ```text
-///  |--------|  |-|  |-------|
-/// low     high l h low    high
-/// ```
-#[derive(Clone, Debug)]
-pub struct DisjointRange<T> {
-    ranges: Vec<UnaryRange<T>>,
-}
+    /// The complement (or "inverse") of this range, without consuming it
+    ///
+    /// See [`UnaryRange::complement`]
+    pub fn complement_ref(&self) -> Option<DisjointRange<T>> {
+        (*self).complement()
+    }
 
-impl<T> DisjointRange<T>
-where
-    T: Copy + Clone + Ord + Bounded + Stepped,
-{
-    /// Create a new (contiguous) range with a single `low` and
-    /// `high` value
-    pub fn new_single_range(low: T, high: T) -> Option<Self> {
-        UnaryRange::new(low, high).map(|r| Self { ranges: vec![r] })
+    /// The parts of `universe` not covered by this range
+    ///
+    /// Unlike [`UnaryRange::complement`], this is bounded by `universe` rather than
+    /// spanning every value of `T`.
+    pub fn complement_within(&self, universe: &UnaryRange<T>) -> DisjointRange<T> {
+        universe
+            .without_ref(self)
+            .map_or_else(DisjointRange::empty, DisjointRange::from_ranges)
     }
+}
 
-    /// Create a new (contiguous) range with a single `low` and
-    /// `high` value
+impl UnaryRange<f32> {
+    /// Create a new [`UnaryRange`] from finite `low` and `high` values
     ///
-    /// `low` > `high` will result in undesired behavior
-    pub fn new_single_range_unchecked(low: T, high: T) -> Self {
-        Self {
-            ranges: vec![UnaryRange::new_unchecked(low, high)],
+    /// Returns `None` if either value is infinite or `NaN`, or if `low > high`
+    pub fn new_finite(low: f32, high: f32) -> Option<Self> {
+        if low.is_finite() && high.is_finite() {
+            Self::new(low, high)
+        } else {
+            None
         }
     }
+}
 
-    /// Create a new range from a vector of [`UnaryRange`]s
-    pub fn from_ranges(ranges: Vec<UnaryRange<T>>) -> Self {
-        Self { ranges }
+impl UnaryRange<f64> {
+    /// Create a new [`UnaryRange`] from finite `low` and `high` values
+    ///
+    /// Returns `None` if either value is infinite or `NaN`, or if `low > high`
+    pub fn new_finite(low: f64, high: f64) -> Option<Self> {
+        if low.is_finite() && high.is_finite() {
+            Self::new(low, high)
+        } else {
+            None
+        }
     }
+}
 
-    /// Create a new range from a series of `(low, high)` pairs
+impl UnaryRange<Ipv4Addr> {
+    /// Parse a CIDR block (e.g. `10.0.0.0/8`) into the addresses it covers
     ///
-    /// If any `(low, high)` pair has `low > high`, undesired behavior will result
-    pub fn from_bounds_unchecked<I: IntoIterator<Item = (T, T)>>(bounds: I) -> Self {
-        Self {
-            ranges: bounds
-                .into_iter()
-                .map(|(low, high)| UnaryRange { low, high })
-                .collect(),
+    /// The address must be the start of the block, i.e. have no host bits set
+    pub fn from_cidr_v4(cidr: &str) -> Result<Self, RangeError> {
+        let (addr, prefix) = cidr.split_once('/').ok_or(RangeError::InvalidCidr)?;
+        let addr: Ipv4Addr = addr.parse().map_err(|_| RangeError::InvalidCidr)?;
+        let prefix: u32 = prefix.parse().map_err(|_| RangeError::InvalidCidr)?;
+        if prefix > 32 {
+            return Err(RangeError::InvalidCidr);
+        }
+        let host_mask = u32::MAX.checked_shr(prefix).unwrap_or(0);
+        let low = addr.to_bits();
+        if low & host_mask != 0 {
+            return Err(RangeError::InvalidCidr);
         }
+        Ok(Self::new_unchecked(
+            addr,
+            Ipv4Addr::from_bits(low | host_mask),
+        ))
     }
 
-    /// Create a new range from a series of `(low, high)` pairs
-    pub fn from_bounds<I: IntoIterator<Item = (T, T)>>(bounds: I) -> Option<Self> {
-        bounds
-            .into_iter()
-            .map(|(low, high)| UnaryRange::new(low, high))
-            .collect::<Option<Vec<UnaryRange<T>>>>()
-            .map(Self::from_ranges)
+    /// Decompose into the fewest CIDR blocks covering exactly this range, as
+    /// `(network address, prefix length)` pairs in ascending order
+    pub fn to_cidrs(&self) -> Vec<(Ipv4Addr, u8)> {
+        let mut out = Vec::new();
+        // widened so that stepping past `255.255.255.255` can't overflow
+        let mut low = u64::from(self.low.to_bits());
+        let high = u64::from(self.high.to_bits());
+        while low <= high {
+            let mut host_bits = min(low.trailing_zeros(), 32);
+            while low + (1 << host_bits) - 1 > high {
+                host_bits -= 1;
+            }
+            out.push((Ipv4Addr::from_bits(low as u32), (32 - host_bits) as u8));
+            low += 1 << host_bits;
+        }
+        out
     }
+}
 
-    /// Create an empty range
-    pub fn empty() -> Self {
-        Self { ranges: Vec::new() }
+impl<T> RangeBounds<T> for UnaryRange<T> {
+    fn start_bound(&self) -> Bound<&T> {
+        Bound::Included(&self.low)
     }
 
-    /// Create a range that covers all values
-    pub fn entire() -> Self {
-        Self::new_single_range_unchecked(bounded_min(), bounded_max())
+    fn end_bound(&self) -> Bound<&T> {
+        Bound::Included(&self.high)
     }
+}
 
-    /// Test whether the range contains `val`
-    pub fn contains(&self, val: T) -> bool {
-        for range in self.ranges.iter() {
-            if range.contains(&val) {
-                return true;
+/// A range with gaps
+///
+/// ```text
+///  |--------|  |-|  |-------|
+/// low     high l h low    high
+/// ```
+///
+/// The contained ranges are always kept in ascending order, without overlapping or
+/// touching: each range's `high` is more than one [step](Stepped::STEP) below the next
+/// range's `low`. Every method that exposes them, like [`DisjointRange::as_slice`],
+/// [`DisjointRange::ranges_iter`] and [`DisjointRange::get`], can be relied on to
+/// follow that order. The only way to break it is to pass unsorted ranges to
+/// [`DisjointRange::from_sorted_disjoint_unchecked`].
+///
+/// Each `DisjointRange` also has a [`StepMode`], chosen with
+/// [`DisjointRange::from_ranges_with_mode`] or [`DisjointRange::with_step_mode`]. It's
+/// [`StepMode::Saturating`] unless stated otherwise, and carries over to the
+/// `DisjointRange`s derived from this one.
+#[derive(Clone)]
+pub struct DisjointRange<T> {
+    ranges: Vec<UnaryRange<T>>,
+    mode: StepMode,
+}
+
+/// How a [`DisjointRange`] steps past its ranges' ends when melding them and taking
+/// complements
+///
+/// Both modes give the same answers as long as `T`'s [`Stepped`] impl saturates at
+/// [`Bounded::MIN_VAL`] and [`Bounded::MAX_VAL`], as it's expected to. They only differ for
+/// impls that don't, e.g. ones that wrap around from `MAX_VAL` to `MIN_VAL`, which
+/// [`StepMode::Checked`] handles exactly without having to change the impl.
+///
+/// ```
+/// use disjoint_ranges::{DisjointRange, StepMode, UnaryRange};
+///
+/// let range = DisjointRange::from_ranges_with_mode(
+///     vec![UnaryRange::new_unchecked(0u8, 9), UnaryRange::new_unchecked(250, u8::MAX)],
+///     StepMode::Checked,
+/// );
+/// assert_eq!(StepMode::Checked, range.step_mode());
+/// assert_eq!(Some((10, 249)), range.complement().get(0).map(|r| r.as_bounds()));
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum StepMode {
+    /// Step with [`Stepped::increment`] and [`Stepped::decrement`], taking a step that
+    /// doesn't change the value to mean a bound was hit
+    ///
+    /// This is the default.
+    #[default]
+    Saturating,
+    /// Step with [`Stepped::checked_increment`] and [`Stepped::checked_decrement`], so the
+    /// bounds are exact even if stepping past them doesn't saturate
+    Checked,
+}
+
+impl StepMode {
+    fn step_up<T: Stepped>(self, val: &T) -> Option<T> {
+        match self {
+            StepMode::Saturating => {
+                let next = val.increment();
+                (next != *val).then_some(next)
             }
+            StepMode::Checked => val.checked_increment(),
         }
-        false
     }
 
-    /// Combine this `DisjointRange` with another, maintaining order and merging
-    pub fn add_disjoint_range(&mut self, other: DisjointRange<T>) {
-        self.ranges.extend(other.ranges);
-        DisjointRange::sort_ranges(&mut self.ranges);
-        DisjointRange::meld_ranges_unchecked(&mut self.ranges);
+    fn step_down<T: Stepped>(self, val: &T) -> Option<T> {
+        match self {
+            StepMode::Saturating => {
+                let next = val.decrement();
+                (next != *val).then_some(next)
+            }
+            StepMode::Checked => val.checked_decrement(),
+        }
     }
+}
 
-    /// Add a [`UnaryRange`] to this `DisjointRange`, maintaining order and merging
-    pub fn add_unary_range(&mut self, to_add: UnaryRange<T>) {
-        let l = self.ranges.len();
-        // let mut out = self.ranges;
-        let mut i = 0;
-        let mut inserted = false;
-        while i < l {
-            if to_add.low < self.ranges[i].low {
-                self.ranges.insert(i, to_add);
-                inserted = true;
-                break;
-            }
-            i += 1;
+/// Formats as e.g. `[1..=5, 8, 12..=20]`, or with the full [`UnaryRange`]s when using `{:#?}`
+impl<T> fmt::Debug for DisjointRange<T>
+where
+    T: fmt::Debug + PartialEq,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            return f
+                .debug_struct("DisjointRange")
+                .field("ranges", &self.ranges)
+                .finish();
         }
-        if !inserted {
-            self.ranges.push(to_add);
+        write!(f, "[")?;
+        for (i, range) in self.ranges.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            if range.low == range.high {
+                write!(f, "{:?}", range.low)?;
+            } else {
+                write!(f, "{:?}..={:?}", range.low, range.high)?;
+            }
         }
-        DisjointRange::meld_ranges_unchecked(&mut self.ranges);
+        write!(f, "]")
     }
+}
 
-    /// Remove a [`UnaryRange`]('s worth of values) from this `DisjointRange`, maintaining order
-    /// and merging
-    pub fn subtract_unary_range(&mut self, to_remove: UnaryRange<T>) {
-        let orig_len = self.ranges.len();
-        let mut i = 0;
-        while i < orig_len {
-            if self.ranges[i].low > to_remove.high {
-                break;
-            } else if self.ranges[i].low <= to_remove.high || self.ranges[1].high >= to_remove.low {
-                let target = self.ranges.remove(i);
-                if let Some(new_ranges) = target.without(to_remove) {
-                    let insert_len = new_ranges.len();
-                    for new_range in new_ranges.into_iter().rev() {
-                        self.ranges.insert(i, new_range);
-                    }
-                    if insert_len == 2 {
-                        break; // to_remove entirely contained w/in out[i], we can stop
-                    }
-                }
+/// Formats compactly as e.g. `1-5,8,12-20`, which can be parsed back with [`str::parse`]
+impl<T> fmt::Display for DisjointRange<T>
+where
+    T: fmt::Display + PartialEq,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, range) in self.ranges.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            if range.low == range.high {
+                write!(f, "{}", range.low)?;
+            } else {
+                write!(f, "{}-{}", range.low, range.high)?;
             }
-            i += 1;
         }
+        Ok(())
     }
+}
 
-    /// The complement (or "inverse") of this range
-    ///
-    /// This is the combination of the complement of the [`UnaryRange`]s this
-    /// `DisjointRange` contains
-    pub fn complement(self) -> Self {
-        let s = self.clone();
-        let mut out = self
-            .ranges
-            .into_iter()
-            .flat_map(UnaryRange::complement_ranges)
-            .collect();
-        DisjointRange::sort_ranges(&mut out);
-        DisjointRange::meld_ranges_unchecked(&mut out);
-        let mut out = Self { ranges: out };
-        for r in s.ranges.into_iter() {
-            out.subtract_unary_range(r);
+/// Parses the compact format produced by [`fmt::Display`], e.g. `1-5,8,12-20`
+///
+/// The ranges can be in any order and may overlap; they're sorted and melded. Whitespace
+/// around each range is ignored, and an empty string is an empty `DisjointRange`.
+/// Negative values are fine too, e.g. `-10--5`.
+impl<T> FromStr for DisjointRange<T>
+where
+    T: Clone + Ord + Stepped + FromStr,
+{
+    type Err = RangeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim().is_empty() {
+            return Ok(Self::empty());
         }
-        DisjointRange::meld_ranges_unchecked(&mut out.ranges);
-        out
+        let mut ranges = s
+            .split(',')
+            .map(|part| parse_compact_range(part.trim()))
+            .collect::<Result<Vec<UnaryRange<T>>, RangeError>>()?;
+        DisjointRange::meld_ranges(&mut ranges);
+        Ok(Self {
+            ranges,
+            mode: StepMode::Saturating,
+        })
     }
+}
 
-    /// Iterator over the contained ranges
-    pub fn ranges_iter(&self) -> RangesIter<T> {
-        let ranges = VecDeque::from(self.ranges.clone());
-        RangesIter { ranges }
+/// Parse a single `low-high` or `val` from the compact format
+fn parse_compact_range<T>(part: &str) -> Result<UnaryRange<T>, RangeError>
+where
+    T: Clone + PartialOrd + FromStr,
+{
+    if let Ok(val) = part.parse::<T>() {
+        return Ok(UnaryRange::new_unchecked(val.clone(), val));
     }
+    // the separator can't be the first character, which would be a minus sign
+    for (idx, _) in part.match_indices('-').filter(|(idx, _)| *idx > 0) {
+        if let (Ok(low), Ok(high)) = (part[..idx].parse(), part[idx + 1..].parse()) {
+            return UnaryRange::new(low, high).ok_or(RangeError::InvertedBounds);
+        }
+    }
+    Err(RangeError::InvalidFormat)
+}
 
-    /// Sort a `Vec<UnaryRange<T>>` by (low)[`UnaryRange::low`] so that it
-    /// can be properly [melded](`DisjointRange::meld_ranges_unchecked`)
-    pub fn sort_ranges(ranges: &mut Vec<UnaryRange<T>>) {
-        ranges.sort_by_cached_key(|UnaryRange { low, .. }: &UnaryRange<T>| *low);
+/// Comparisons only, so these don't need `T: Stepped`
+impl<T> DisjointRange<T>
+where
+    T: Clone + Ord,
+{
+    /// Test whether the range contains `val`
+    pub fn contains(&self, val: &T) -> bool {
+        self.range_containing(val).is_some()
     }
 
-    /// Meld the contents of a `Vec<UnaryRange<T>>` by combining ranges with adjacent
-    /// [high](`UnaryRange::high`) and [low](`UnaryRange::low`) values
+    /// Test whether the range contains `val`, taking it by value
+    #[deprecated(since = "0.6.0", note = "use `contains` instead, which takes `&T`")]
+    pub fn contains_value(&self, val: T) -> bool {
+        self.contains(&val)
+    }
+
+    /// Test whether the range contains `val` after converting it to a `T`
     ///
-    /// Calling this before correctly sorting the vector (with [`DisjointRange::sort_ranges`])
-    /// will result in undesired behavior
-    pub fn meld_ranges_unchecked(ranges: &mut Vec<UnaryRange<T>>) {
-        let mut i = 0;
-        let mut l = ranges.len();
-        while i + 1 < l {
-            if ranges[i + 1].low <= ranges[i].high.increment() {
-                ranges[i + 1].low = min(ranges[i].low, ranges[i + 1].low);
-                ranges[i + 1].high = max(ranges[i].high, ranges[i + 1].high);
-                ranges.remove(i);
-                l -= 1;
+    /// Values that don't fit in `T` (i.e. whose conversion fails) simply aren't
+    /// contained, so e.g. a `DisjointRange<u16>` can be checked against any `u32`.
+    pub fn contains_converted<U: TryInto<T>>(&self, val: U) -> bool {
+        val.try_into().is_ok_and(|val| self.contains(&val))
+    }
+
+    /// The contained range that `val` falls within, if any
+    pub fn range_containing(&self, val: &T) -> Option<&UnaryRange<T>> {
+        self.ranges
+            .get(self.locate(val))
+            .filter(|range| range.low <= *val)
+    }
+
+    /// Test whether every value in `range` is contained, e.g.
+    /// `set.contains_inclusive(10..=20)`
+    ///
+    /// An empty `range` (e.g. `20..=10`) is rejected and returns `false`, unlike with
+    /// [`DisjointRange::contains_bounds`], which treats it as trivially contained.
+    pub fn contains_inclusive(&self, range: RangeInclusive<T>) -> bool {
+        if range.is_empty() {
+            return false;
+        }
+        let wanted = UnaryRange::new_unchecked(range.start().clone(), range.end().clone());
+        self.range_containing(&wanted.low)
+            .is_some_and(|range| range.contains_range(&wanted))
+    }
+
+    /// Test whether the range shares any values with `other`
+    ///
+    /// This is done in a single pass over both ranges, stopping at the first overlap.
+    pub fn overlaps(&self, other: &DisjointRange<T>) -> bool {
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (mine, theirs) = (&self.ranges[i], &other.ranges[j]);
+            if mine.overlaps(theirs) {
+                return true;
+            }
+            if mine.high < theirs.high {
+                i += 1;
             } else {
+                j += 1;
+            }
+        }
+        false
+    }
+
+    /// Keep only the values that are also in another `DisjointRange`, maintaining order
+    ///
+    /// This is done in a single pass over both ranges.
+    pub fn intersect_with(&mut self, other: &DisjointRange<T>) {
+        let mut out = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (mine, theirs) = (&self.ranges[i], &other.ranges[j]);
+            let low = max(&mine.low, &theirs.low);
+            let high = min(&mine.high, &theirs.high);
+            if low <= high {
+                out.push(UnaryRange::new_unchecked(low.clone(), high.clone()));
+            }
+            if mine.high < theirs.high {
                 i += 1;
+            } else {
+                j += 1;
             }
         }
+        self.ranges = out;
     }
-    /// Sort and meld the contents of a `Vec<UnaryRange<T>>` by combining ranges with
-    /// adjacent [high](`UnaryRange::high`) and [low](`UnaryRange::low`) values
-    pub fn meld_ranges(ranges: &mut Vec<UnaryRange<T>>) {
-        DisjointRange::sort_ranges(ranges);
-        DisjointRange::meld_ranges_unchecked(ranges);
+
+    /// Index of the first range whose `high` is `>= val`, found by binary search
+    ///
+    /// If `val` is contained, it's contained in the range at this index.
+    fn locate(&self, val: &T) -> usize {
+        self.ranges.partition_point(|range| range.high < *val)
     }
 }
 
-pub struct RangesIter<T> {
-    ranges: std::collections::VecDeque<UnaryRange<T>>,
+/// Construction and melding only, so these work for non-`Copy` `T`s too
+impl<T> DisjointRange<T>
+where
+    T: Clone + Ord + Stepped,
+{
+    /// Create a new (contiguous) range with a single `low` and
+    /// `high` value
+    pub fn new_single_range(low: T, high: T) -> Option<Self> {
+        UnaryRange::new(low, high).map(|r| Self {
+            ranges: vec![r],
+            mode: StepMode::Saturating,
+        })
+    }
+
+    /// Create a new range from a series of `(low, high)` pairs
+    ///
+    /// The pairs can be in any order and may overlap; they're sorted and melded.
+    /// Returns `None` if any pair has `low > high`.
+    pub fn from_bounds<I: IntoIterator<Item = (T, T)>>(bounds: I) -> Option<Self> {
+        bounds
+            .into_iter()
+            .map(|(low, high)| UnaryRange::new(low, high))
+            .collect::<Option<Vec<UnaryRange<T>>>>()
+            .map(Self::from_ranges)
+    }
+
+    /// Create a new range from a series of [`RangeInclusive`]s
+    ///
+    /// The ranges can be in any order and may overlap; they're sorted and melded.
+    /// Returns `None` if any of them is empty (e.g. `10..=0`).
+    pub fn from_inclusive_ranges<I: IntoIterator<Item = RangeInclusive<T>>>(
+        ranges: I,
+    ) -> Option<Self> {
+        ranges
+            .into_iter()
+            .map(|range| {
+                if range.is_empty() {
+                    None
+                } else {
+                    UnaryRange::new(range.start().clone(), range.end().clone())
+                }
+            })
+            .collect::<Option<Vec<UnaryRange<T>>>>()
+            .map(Self::from_ranges)
+    }
+
+    /// Create a new (contiguous) range with a single `low` and
+    /// `high` value
+    ///
+    /// `low` > `high` will result in undesired behavior
+    pub fn new_single_range_unchecked(low: T, high: T) -> Self {
+        Self {
+            ranges: vec![UnaryRange::new_unchecked(low, high)],
+            mode: StepMode::Saturating,
+        }
+    }
+
+    /// Create a new range from a vector of [`UnaryRange`]s
+    ///
+    /// The ranges can be in any order and may overlap; they're sorted and melded.
+    pub fn from_ranges(ranges: Vec<UnaryRange<T>>) -> Self {
+        Self::from_ranges_with_mode(ranges, StepMode::Saturating)
+    }
+
+    /// Create a new range from a vector of [`UnaryRange`]s, stepping with `mode` from
+    /// now on
+    ///
+    /// Like [`DisjointRange::from_ranges`], the ranges are sorted and melded (with `mode`).
+    pub fn from_ranges_with_mode(mut ranges: Vec<UnaryRange<T>>, mode: StepMode) -> Self {
+        DisjointRange::meld_ranges_with_mode(&mut ranges, mode);
+        Self { ranges, mode }
+    }
+
+    /// The [`StepMode`] used when melding and taking complements
+    pub fn step_mode(&self) -> StepMode {
+        self.mode
+    }
+
+    /// Switch to stepping with `mode`, re-melding the contained ranges with it
+    pub fn with_step_mode(mut self, mode: StepMode) -> Self {
+        self.mode = mode;
+        DisjointRange::meld_ranges_with_mode(&mut self.ranges, mode);
+        self
+    }
+
+    /// A new `DisjointRange` with the same [`StepMode`] as this one
+    fn with_ranges(&self, ranges: Vec<UnaryRange<T>>) -> Self {
+        Self {
+            ranges,
+            mode: self.mode,
+        }
+    }
+
+    /// Create a new range from a vector of [`UnaryRange`]s that's already sorted and melded
+    ///
+    /// Unlike [`DisjointRange::from_ranges`], this does no work at all, so it's up to the
+    /// caller to make sure every range has `low <= high`, the ranges are sorted, and no
+    /// two of them overlap or are adjacent. Otherwise, undesired behavior will result.
+    /// Debug builds check this with [`DisjointRange::is_canonical`].
+    pub fn from_sorted_disjoint_unchecked(ranges: Vec<UnaryRange<T>>) -> Self {
+        let out = Self {
+            ranges,
+            mode: StepMode::Saturating,
+        };
+        debug_assert!(out.is_canonical(), "ranges aren't sorted and melded");
+        out
+    }
+
+    /// Test whether the contained ranges are sorted and melded, i.e. every range has
+    /// `low <= high` and is separated from the next one by a gap of at least one value
+    ///
+    /// This is always true unless the range was built with one of the `_unchecked`
+    /// constructors.
+    pub fn is_canonical(&self) -> bool {
+        self.ranges.iter().all(|range| range.low <= range.high)
+            && self.ranges.windows(2).all(|pair| {
+                self.mode
+                    .step_up(&pair[0].high)
+                    .is_some_and(|after| after < pair[1].low)
+            })
+    }
+
+    /// Create a new range from a series of `(low, high)` pairs
+    ///
+    /// The pairs can be in any order and may overlap; they're sorted and melded.
+    ///
+    /// If any `(low, high)` pair has `low > high`, undesired behavior will result
+    pub fn from_bounds_unchecked<I: IntoIterator<Item = (T, T)>>(bounds: I) -> Self {
+        Self::from_ranges(
+            bounds
+                .into_iter()
+                .map(|(low, high)| UnaryRange { low, high })
+                .collect(),
+        )
+    }
+
+    /// Create an empty range
+    ///
+    /// This is a `const fn`, so it can be used in `const`s and `static`s
+    pub const fn empty() -> Self {
+        Self {
+            ranges: Vec::new(),
+            mode: StepMode::Saturating,
+        }
+    }
+
+    /// Restore the sorted, melded invariant
+    ///
+    /// The ranges are sorted first, so they can be in any order. Every constructor
+    /// already does this, so it's only needed if the ranges were assembled in some
+    /// other way.
+    pub fn meld(&mut self) {
+        DisjointRange::meld_ranges_with_mode(&mut self.ranges, self.mode);
+    }
+
+    /// Sort and meld `ranges` like [`DisjointRange::from_ranges`], also reporting which
+    /// of them were combined
+    ///
+    /// Every time one range is melded into another because they overlap or are adjacent,
+    /// the pair of their indices in `ranges` is reported, lower index first. When a range
+    /// touches several others that were already combined, it's paired with the one
+    /// reaching furthest. The pairs are sorted.
+    pub fn normalize_reporting(ranges: Vec<UnaryRange<T>>) -> (Self, Vec<(usize, usize)>) {
+        DisjointRange::normalize_reporting_with_mode(ranges, StepMode::Saturating)
+    }
+
+    /// Sort and meld `ranges` like [`DisjointRange::normalize_reporting`], stepping with
+    /// `mode`
+    pub fn normalize_reporting_with_mode(
+        ranges: Vec<UnaryRange<T>>,
+        mode: StepMode,
+    ) -> (Self, Vec<(usize, usize)>) {
+        let mut indexed: Vec<(usize, UnaryRange<T>)> = ranges.into_iter().enumerate().collect();
+        indexed.sort_by(|(_, a), (_, b)| a.low.cmp(&b.low));
+        let mut out: Vec<UnaryRange<T>> = Vec::with_capacity(indexed.len());
+        let mut merged = Vec::new();
+        // the index of the input reaching furthest in the last range of `out`
+        let mut furthest = 0;
+        for (idx, range) in indexed {
+            match out.last_mut() {
+                Some(last)
+                    if mode
+                        .step_up(&last.high)
+                        .is_none_or(|after| range.low <= after) =>
+                {
+                    merged.push((min(furthest, idx), max(furthest, idx)));
+                    if range.high > last.high {
+                        last.high = range.high.clone();
+                        furthest = idx;
+                    }
+                }
+                _ => {
+                    out.push(range);
+                    furthest = idx;
+                }
+            }
+        }
+        merged.sort_unstable();
+        (Self { ranges: out, mode }, merged)
+    }
+
+    /// Sort a `Vec<UnaryRange<T>>` by (low)[`UnaryRange::low`] so that it
+    /// can be properly [melded](`DisjointRange::meld_ranges_unchecked`)
+    pub fn sort_ranges(ranges: &mut [UnaryRange<T>]) {
+        ranges.sort_by_cached_key(|UnaryRange { low, .. }: &UnaryRange<T>| low.clone());
+    }
+
+    /// Meld the contents of a `Vec<UnaryRange<T>>` by combining ranges with adjacent
+    /// [high](`UnaryRange::high`) and [low](`UnaryRange::low`) values
+    ///
+    /// Calling this before correctly sorting the vector (with [`DisjointRange::sort_ranges`])
+    /// will result in undesired behavior
+    pub fn meld_ranges_unchecked(ranges: &mut Vec<UnaryRange<T>>) {
+        DisjointRange::meld_with_mode_unchecked(ranges, StepMode::Saturating);
+    }
+
+    fn meld_with_mode_unchecked(ranges: &mut Vec<UnaryRange<T>>, mode: StepMode) {
+        let mut i = 0;
+        let mut l = ranges.len();
+        while i + 1 < l {
+            // a range ending at `T::MAX_VAL` swallows everything after it
+            let touching = mode
+                .step_up(&ranges[i].high)
+                .is_none_or(|after| ranges[i + 1].low <= after);
+            if touching {
+                let lower = ranges.remove(i);
+                let upper = &mut ranges[i];
+                if lower.low < upper.low {
+                    upper.low = lower.low;
+                }
+                if lower.high > upper.high {
+                    upper.high = lower.high;
+                }
+                l -= 1;
+            } else {
+                i += 1;
+            }
+        }
+    }
+
+    /// Sort and meld the contents of a `Vec<UnaryRange<T>>` by combining ranges with
+    /// adjacent [high](`UnaryRange::high`) and [low](`UnaryRange::low`) values
+    pub fn meld_ranges(ranges: &mut Vec<UnaryRange<T>>) {
+        DisjointRange::meld_ranges_with_mode(ranges, StepMode::Saturating);
+    }
+
+    /// Sort and meld the contents of a `Vec<UnaryRange<T>>` like
+    /// [`DisjointRange::meld_ranges`], stepping with `mode`
+    pub fn meld_ranges_with_mode(ranges: &mut Vec<UnaryRange<T>>, mode: StepMode) {
+        DisjointRange::sort_ranges(ranges);
+        DisjointRange::meld_with_mode_unchecked(ranges, mode);
+    }
 }
 
-impl<T> Iterator for RangesIter<T> {
-    type Item = UnaryRange<T>;
+impl<T> DisjointRange<T>
+where
+    T: Copy + Clone + Ord + Stepped,
+{
+    /// Create a range containing every value in `domain` for which `pred` returns `true`
+    ///
+    /// Consecutive matching values are coalesced into ranges. `pred` is called once per
+    /// value in `domain`, making this O(domain size), so it's only sensible for small,
+    /// bounded domains.
+    pub fn from_predicate<F: FnMut(&T) -> bool>(domain: UnaryRange<T>, pred: F) -> Self {
+        let mut out = Self {
+            ranges: vec![domain],
+            mode: StepMode::Saturating,
+        };
+        out.retain_values(pred);
+        out
+    }
+
+    /// Test whether `self` and `other` contain exactly the same values
+    ///
+    /// Unlike comparing the contained ranges directly, this doesn't depend on either
+    /// side being [canonical](DisjointRange::is_canonical): ranges built with the
+    /// `_unchecked` constructors are sorted and melded (dropping any with
+    /// `low > high`) before they're compared.
+    pub fn covers_same(&self, other: &DisjointRange<T>) -> bool {
+        if self.is_canonical() && other.is_canonical() {
+            return self.ranges == other.ranges;
+        }
+        let normalized = |ranges: &[UnaryRange<T>]| {
+            let mut ranges: Vec<UnaryRange<T>> = ranges
+                .iter()
+                .filter(|range| range.low <= range.high)
+                .copied()
+                .collect();
+            DisjointRange::meld_ranges_with_mode(&mut ranges, self.mode);
+            ranges
+        };
+        normalized(&self.ranges) == normalized(&other.ranges)
+    }
+
+    /// Combine this `DisjointRange` with another, maintaining order and merging
+    #[deprecated(since = "0.6.0", note = "use `union_with` instead")]
+    pub fn add_disjoint_range(&mut self, other: DisjointRange<T>) {
+        self.union_with(&other);
+    }
+
+    /// Add all the values in another `DisjointRange` to this one, maintaining order and merging
+    ///
+    /// This is done in a single pass over both ranges.
+    pub fn union_with(&mut self, other: &DisjointRange<T>) {
+        let mut out: Vec<UnaryRange<T>> =
+            Vec::with_capacity(self.ranges.len() + other.ranges.len());
+        let mut mine = self.ranges.iter().peekable();
+        let mut theirs = other.ranges.iter().peekable();
+        loop {
+            let next = match (mine.peek(), theirs.peek()) {
+                (Some(a), Some(b)) if b.low < a.low => theirs.next(),
+                (Some(_), _) => mine.next(),
+                (None, _) => theirs.next(),
+            };
+            let Some(&next) = next else {
+                break;
+            };
+            match out.last_mut() {
+                Some(last)
+                    if self
+                        .mode
+                        .step_up(&last.high)
+                        .is_none_or(|after| next.low <= after) =>
+                {
+                    last.high = max(last.high, next.high);
+                }
+                _ => out.push(next),
+            }
+        }
+        self.ranges = out;
+    }
+
+    /// Test whether every value in `self` is contained in at least one of `sets`
+    ///
+    /// Equivalent to checking that `self` is a subset of the union of `sets`, but
+    /// without building that union: all of them are walked in step with `self`, stopping
+    /// at the first value that none of them contain.
+    pub fn is_covered_by<I: IntoIterator<Item = DisjointRange<T>>>(&self, sets: I) -> bool {
+        let sets: Vec<DisjointRange<T>> = sets.into_iter().collect();
+        let mut cursors = vec![0; sets.len()];
+        for range in self.ranges.iter() {
+            let mut low = range.low;
+            loop {
+                // the furthest any one set reaches, starting from `low`
+                let mut reach: Option<T> = None;
+                for (set, cursor) in sets.iter().zip(cursors.iter_mut()) {
+                    while *cursor < set.ranges.len() && set.ranges[*cursor].high < low {
+                        *cursor += 1;
+                    }
+                    if let Some(candidate) = set.ranges.get(*cursor)
+                        && candidate.low <= low
+                    {
+                        reach = max(reach, Some(candidate.high));
+                    }
+                }
+                let Some(high) = reach else {
+                    return false;
+                };
+                if high >= range.high {
+                    break;
+                }
+                low = high.increment();
+            }
+        }
+        true
+    }
+
+    /// The number of values contained in both `self` and `other`
+    ///
+    /// Equivalent to intersecting and calling [`DisjointRange::count`] (including
+    /// saturating at `u128::MAX`), but without building the intersection.
+    pub fn intersection_count(&self, other: &DisjointRange<T>) -> u128 {
+        let mut total: u128 = 0;
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (mine, theirs) = (self.ranges[i], other.ranges[j]);
+            total = total.saturating_add(mine.overlap_len(&theirs));
+            if mine.high < theirs.high {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        total
+    }
+
+    /// Segment every value in either `self` or `other` into maximal runs, tagged
+    /// by which of the two they're in
+    ///
+    /// The segments are in ascending order. Values in neither are skipped.
+    pub fn overlay(&self, other: &DisjointRange<T>) -> Vec<(UnaryRange<T>, Membership)> {
+        let mut out = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        let mut mine = self.ranges.first().copied();
+        let mut theirs = other.ranges.first().copied();
+        while let (Some(left), Some(right)) = (mine.as_mut(), theirs.as_mut()) {
+            if left.high < right.low {
+                out.push((*left, Membership::OnlyLeft));
+                i += 1;
+                mine = self.ranges.get(i).copied();
+            } else if right.high < left.low {
+                out.push((*right, Membership::OnlyRight));
+                j += 1;
+                theirs = other.ranges.get(j).copied();
+            } else if left.low < right.low {
+                let before = UnaryRange::new_unchecked(left.low, right.low.decrement());
+                out.push((before, Membership::OnlyLeft));
+                left.low = right.low;
+            } else if right.low < left.low {
+                let before = UnaryRange::new_unchecked(right.low, left.low.decrement());
+                out.push((before, Membership::OnlyRight));
+                right.low = left.low;
+            } else {
+                let high = min(left.high, right.high);
+                out.push((UnaryRange::new_unchecked(left.low, high), Membership::Both));
+                if left.high == high {
+                    i += 1;
+                    mine = self.ranges.get(i).copied();
+                } else {
+                    left.low = high.increment();
+                }
+                if right.high == high {
+                    j += 1;
+                    theirs = other.ranges.get(j).copied();
+                } else {
+                    right.low = high.increment();
+                }
+            }
+        }
+        // at most one side has anything left
+        let rest_left = mine
+            .into_iter()
+            .chain(self.ranges.iter().skip(i + 1).copied());
+        out.extend(rest_left.map(|range| (range, Membership::OnlyLeft)));
+        let rest_right = theirs
+            .into_iter()
+            .chain(other.ranges.iter().skip(j + 1).copied());
+        out.extend(rest_right.map(|range| (range, Membership::OnlyRight)));
+        out
+    }
+
+    /// Compare `self` against a `newer` version, returning `(added, removed)`
+    ///
+    /// `added` is every value in `newer` but not `self`, and `removed` every value in
+    /// `self` but not `newer`. Both are computed in a single pass over both ranges.
+    pub fn diff(&self, newer: &DisjointRange<T>) -> (DisjointRange<T>, DisjointRange<T>) {
+        let (mut added, mut removed) = (Vec::new(), Vec::new());
+        for (range, membership) in self.overlay(newer) {
+            match membership {
+                Membership::OnlyLeft => removed.push(range),
+                Membership::OnlyRight => added.push(range),
+                Membership::Both => {}
+            }
+        }
+        (self.with_ranges(added), self.with_ranges(removed))
+    }
+
+    /// Add a [`UnaryRange`] to this `DisjointRange`, maintaining order and merging
+    ///
+    /// This relies on the existing ranges being sorted and melded, which every
+    /// constructor except [`DisjointRange::from_sorted_disjoint_unchecked`] guarantees
+    /// (see [`DisjointRange::is_canonical`]). In debug builds, that's asserted.
+    pub fn add_unary_range(&mut self, to_add: UnaryRange<T>) {
+        debug_assert!(self.is_canonical(), "ranges aren't sorted and melded");
+        let idx = self.ranges.partition_point(|range| range.low <= to_add.low);
+        self.ranges.insert(idx, to_add);
+        DisjointRange::meld_with_mode_unchecked(&mut self.ranges, self.mode);
+    }
+
+    /// Add a [`UnaryRange`] like [`DisjointRange::add_unary_range`], returning whether
+    /// that added any new values
+    ///
+    /// Returns `false` (leaving the range untouched) if every value in `to_add` was
+    /// already contained.
+    pub fn insert_range(&mut self, to_add: UnaryRange<T>) -> bool {
+        let covered = self
+            .range_containing(&to_add.low)
+            .is_some_and(|range| range.contains_range(&to_add));
+        if !covered {
+            self.add_unary_range(to_add);
+        }
+        !covered
+    }
+
+    /// Remove all the values in another `DisjointRange` from this one, maintaining order
+    ///
+    /// This is done in a single pass over both ranges.
+    pub fn difference_with(&mut self, other: &DisjointRange<T>) {
+        let mut out = Vec::with_capacity(self.ranges.len());
+        let mut j = 0;
+        for range in self.ranges.iter() {
+            while j < other.ranges.len() && other.ranges[j].high < range.low {
+                j += 1;
+            }
+            let mut low = range.low;
+            let mut remaining = true;
+            while remaining && j < other.ranges.len() && other.ranges[j].low <= range.high {
+                let to_remove = other.ranges[j];
+                if to_remove.low > low {
+                    out.push(UnaryRange::new_unchecked(low, to_remove.low.decrement()));
+                }
+                if to_remove.high >= range.high {
+                    // `to_remove` might cover (part of) the next range too
+                    remaining = false;
+                } else {
+                    low = to_remove.high.increment();
+                    j += 1;
+                }
+            }
+            if remaining {
+                out.push(UnaryRange::new_unchecked(low, range.high));
+            }
+        }
+        self.ranges = out;
+    }
+
+    /// Remove a [`UnaryRange`]('s worth of values) from this `DisjointRange`, maintaining order
+    /// and merging
+    pub fn subtract_unary_range(&mut self, to_remove: UnaryRange<T>) {
+        let (start, end) = self.window_bounds(&to_remove);
+        let kept: Vec<UnaryRange<T>> = self.ranges[start..end]
+            .iter()
+            .flat_map(|range| range.without(to_remove).unwrap_or_default())
+            .collect();
+        self.ranges.splice(start..end, kept);
+    }
+
+    /// Remove a [`UnaryRange`]('s worth of values), returning the values that were
+    /// actually removed
+    ///
+    /// The returned `DisjointRange` is what `self` and `other` had in common beforehand.
+    pub fn subtract_reporting(&mut self, other: &UnaryRange<T>) -> DisjointRange<T> {
+        let (start, end) = self.window_bounds(other);
+        let removed = self.clip(start..end, other);
+        self.subtract_unary_range(*other);
+        self.with_ranges(removed)
+    }
+
+    /// Remove many individual values at once
+    ///
+    /// `points` can be in any order and contain duplicates. They're sorted, then
+    /// removed in a single pass over the contained ranges, rather than searching
+    /// for each one separately.
+    pub fn subtract_points<I: IntoIterator<Item = T>>(&mut self, points: I) {
+        let mut points: Vec<T> = points.into_iter().collect();
+        points.sort_unstable();
+        points.dedup();
+        let mut points = points.into_iter().peekable();
+        let mut out = Vec::with_capacity(self.ranges.len());
+        for range in self.ranges.drain(..) {
+            // `None` once the whole range has been punched out
+            let mut low = Some(range.low);
+            while let Some(point) = points.next_if(|point| *point <= range.high) {
+                let Some(start) = low.filter(|start| *start <= point) else {
+                    continue;
+                };
+                if start < point {
+                    out.push(UnaryRange::new_unchecked(start, point.decrement()));
+                }
+                low = (point < range.high).then(|| point.increment());
+            }
+            if let Some(start) = low {
+                out.push(UnaryRange::new_unchecked(start, range.high));
+            }
+        }
+        self.ranges = out;
+    }
+
+    /// Split into the values `<= at` and the values `> at`
+    ///
+    /// A range containing both `at` and values above it is split in two.
+    pub fn partition(&self, at: T) -> (Self, Self) {
+        let idx = self.ranges.partition_point(|range| range.high <= at);
+        let mut lower = self.ranges[..idx].to_vec();
+        let mut upper = self.ranges[idx..].to_vec();
+        if let Some(straddling) = upper.first_mut()
+            && straddling.low <= at
+        {
+            lower.push(UnaryRange::new_unchecked(straddling.low, at));
+            straddling.low = at.increment();
+        }
+        (self.with_ranges(lower), self.with_ranges(upper))
+    }
+
+    /// Indices of the first range that intersects `window` and the first range
+    /// entirely above it
+    fn window_bounds(&self, window: &UnaryRange<T>) -> (usize, usize) {
+        let start = self.ranges.partition_point(|range| range.high < window.low);
+        let end = self
+            .ranges
+            .partition_point(|range| range.low <= window.high);
+        (start, end)
+    }
+
+    /// The ranges at `indices`, clipped to `window`
+    fn clip(&self, indices: std::ops::Range<usize>, window: &UnaryRange<T>) -> Vec<UnaryRange<T>> {
+        self.ranges[indices]
+            .iter()
+            .map(|range| {
+                UnaryRange::new_unchecked(max(range.low, window.low), min(range.high, window.high))
+            })
+            .collect()
+    }
+
+    /// The values contained both in this range and in `range`
+    ///
+    /// An empty `range` (e.g. `10..=0`) results in an empty `DisjointRange`.
+    pub fn intersect_range(&self, range: RangeInclusive<T>) -> Self {
+        if range.is_empty() {
+            return self.with_ranges(Vec::new());
+        }
+        let window = UnaryRange::new_unchecked(*range.start(), *range.end());
+        let (start, end) = self.window_bounds(&window);
+        self.with_ranges(self.clip(start..end, &window))
+    }
+
+    /// Remove and return the values within `window`
+    ///
+    /// Afterwards `self` only contains values outside of `window`.
+    pub fn drain_window(&mut self, window: UnaryRange<T>) -> Self {
+        let (start, end) = self.window_bounds(&window);
+        let drained = self.clip(start..end, &window);
+        self.subtract_unary_range(window);
+        self.with_ranges(drained)
+    }
+
+    /// Split off the values `>= at` into a new `DisjointRange`, like
+    /// [`BTreeMap::split_off`](std::collections::BTreeMap::split_off)
+    ///
+    /// Afterwards `self` contains only the values `< at`. A range containing both
+    /// `at` and values below it is split in two, with `at` going to the returned range.
+    pub fn split_off(&mut self, at: T) -> Self {
+        let idx = self.ranges.partition_point(|range| range.high < at);
+        let mut upper = self.ranges.split_off(idx);
+        if let Some(straddling) = upper.first_mut()
+            && straddling.low < at
+        {
+            self.ranges
+                .push(UnaryRange::new_unchecked(straddling.low, at.decrement()));
+            straddling.low = at;
+        }
+        self.with_ranges(upper)
+    }
+
+    /// The lowest `n` contained values, as a new `DisjointRange`
+    ///
+    /// The range containing the `n`th value is split, and the whole range is returned
+    /// if it contains `n` or fewer values.
+    pub fn take_first(&self, n: u128) -> Self {
+        let mut ranges = Vec::new();
+        let mut remaining = n;
+        for range in self.ranges.iter() {
+            if remaining == 0 {
+                break;
+            }
+            match range.checked_count() {
+                Some(count) if count <= remaining => {
+                    ranges.push(*range);
+                    remaining -= count;
+                }
+                _ => {
+                    let high = range.low.add_steps(remaining - 1);
+                    ranges.push(UnaryRange::new_unchecked(range.low, high));
+                    break;
+                }
+            }
+        }
+        self.with_ranges(ranges)
+    }
+
+    /// The highest `n` contained values, as a new `DisjointRange`
+    ///
+    /// The counterpart to [`DisjointRange::take_first`].
+    pub fn take_last(&self, n: u128) -> Self {
+        let mut ranges = Vec::new();
+        let mut remaining = n;
+        for range in self.ranges.iter().rev() {
+            if remaining == 0 {
+                break;
+            }
+            match range.checked_count() {
+                Some(count) if count <= remaining => {
+                    ranges.push(*range);
+                    remaining -= count;
+                }
+                _ => {
+                    let low = range.high.sub_steps(remaining - 1);
+                    ranges.push(UnaryRange::new_unchecked(low, range.high));
+                    break;
+                }
+            }
+        }
+        ranges.reverse();
+        self.with_ranges(ranges)
+    }
+
+    /// Move all of `other`'s ranges into this `DisjointRange`, maintaining order and
+    /// merging, and leaving `other` empty
+    ///
+    /// See [`Vec::append`]
+    pub fn append(&mut self, other: &mut DisjointRange<T>) {
+        self.ranges.append(&mut other.ranges);
+        DisjointRange::meld_ranges_with_mode(&mut self.ranges, self.mode);
+    }
+
+    /// The number of steps from `val` to the nearest contained value
+    ///
+    /// This is `Some(0)` if `val` is contained, and `None` if the range is empty.
+    pub fn distance_to(&self, val: T) -> Option<u128> {
+        let idx = self.locate(&val);
+        let below = idx
+            .checked_sub(1)
+            .and_then(|i| self.ranges[i].high.steps_between(&val));
+        let above = self.ranges.get(idx).map(|range| {
+            if range.low <= val {
+                0
+            } else {
+                val.steps_between(&range.low).unwrap_or_default()
+            }
+        });
+        match (below, above) {
+            (Some(below), Some(above)) => Some(min(below, above)),
+            (below, above) => below.or(above),
+        }
+    }
+
+    /// The contained value closest to `val`
+    ///
+    /// This is `val` itself if it's contained, and `None` if the range is empty.
+    /// If `val` falls in a gap exactly halfway between two ranges, the lower value
+    /// is returned.
+    pub fn nearest(&self, val: T) -> Option<T> {
+        let idx = self.locate(&val);
+        let below = idx.checked_sub(1).map(|i| self.ranges[i].high);
+        let above = self.ranges.get(idx).map(|range| max(range.low, val));
+        match (below, above) {
+            (Some(below), Some(above)) => {
+                if below.steps_between(&val) <= val.steps_between(&above) {
+                    Some(below)
+                } else {
+                    Some(above)
+                }
+            }
+            (below, above) => below.or(above),
+        }
+    }
+
+    /// Clamp `val` into the set, rounding up
+    ///
+    /// This is `val` itself if it's contained, otherwise the lowest contained value
+    /// above it. If nothing above `val` is contained, it's the highest contained value
+    /// instead, so this is only `None` if the range is empty. Unlike
+    /// [`DisjointRange::nearest`], distance doesn't matter: `val` in a gap always
+    /// goes to the start of the next range.
+    pub fn clamp_value(&self, val: T) -> Option<T> {
+        match self.ranges.get(self.locate(&val)) {
+            Some(range) => Some(max(range.low, val)),
+            None => self.ranges.last().map(|range| range.high),
+        }
+    }
+
+    /// Remove `val` if it's contained, otherwise add it
+    ///
+    /// Returns whether `val` is contained afterwards.
+    pub fn toggle(&mut self, val: T) -> bool {
+        let idx = self.locate(&val);
+        let point = UnaryRange::new_unchecked(val, val);
+        match self.ranges.get(idx) {
+            Some(range) if range.low <= val => {
+                let remaining = range.without(point).unwrap_or_default();
+                self.ranges.splice(idx..=idx, remaining);
+                false
+            }
+            _ => {
+                self.ranges.insert(idx, point);
+                DisjointRange::meld_with_mode_unchecked(&mut self.ranges, self.mode);
+                true
+            }
+        }
+    }
+
+    /// Keep only the values for which `f` returns `true`
+    ///
+    /// Ranges are split wherever `f` changes its answer, so this can badly
+    /// fragment the set (e.g. dropping every odd value). `f` is called once per
+    /// contained value, making this O(total values) rather than O(ranges).
+    pub fn retain_values<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        let mut kept: Vec<UnaryRange<T>> = Vec::with_capacity(self.ranges.len());
+        for range in self.ranges.drain(..) {
+            let mut run: Option<UnaryRange<T>> = None;
+            for val in range {
+                if f(&val) {
+                    match run.as_mut() {
+                        Some(current) => current.high = val,
+                        None => run = Some(UnaryRange::new_unchecked(val, val)),
+                    }
+                } else if let Some(current) = run.take() {
+                    kept.push(current);
+                }
+            }
+            kept.extend(run);
+        }
+        self.ranges = kept;
+    }
+
+    /// The number of gaps between the contained ranges
+    ///
+    /// An empty range has no gaps.
+    pub fn gap_count(&self) -> usize {
+        self.ranges.len().saturating_sub(1)
+    }
+
+    /// The number of contained ranges that intersect `window`
+    ///
+    /// Both ends of `window` are binary-searched, so nothing is clipped or allocated.
+    pub fn ranges_in_window(&self, window: &UnaryRange<T>) -> usize {
+        let (start, end) = self.window_bounds(window);
+        end.saturating_sub(start)
+    }
+
+    /// Reserve capacity for at least `additional` more [`UnaryRange`]s
+    ///
+    /// See [`Vec::reserve`]
+    pub fn reserve(&mut self, additional: usize) {
+        self.ranges.reserve(additional);
+    }
+
+    /// Shrink the capacity of the underlying storage as much as possible
+    ///
+    /// See [`Vec::shrink_to_fit`]
+    pub fn shrink_to_fit(&mut self) {
+        self.ranges.shrink_to_fit();
+    }
+
+    /// Remove every value, keeping the allocated capacity
+    ///
+    /// See [`Vec::clear`]
+    pub fn clear(&mut self) {
+        self.ranges.clear();
+    }
+
+    /// The number of values in the range
+    ///
+    /// Like [`UnaryRange::count`], this saturates at `u128::MAX`. See
+    /// [`DisjointRange::checked_count`].
+    pub fn count(&self) -> u128 {
+        self.ranges
+            .iter()
+            .fold(0u128, |total, range| total.saturating_add(range.count()))
+    }
+
+    /// The number of values in the range, or `None` if that's more than `u128::MAX`
+    pub fn checked_count(&self) -> Option<u128> {
+        self.ranges.iter().try_fold(0u128, |total, range| {
+            range
+                .checked_count()
+                .and_then(|count| total.checked_add(count))
+        })
+    }
+
+    /// The fraction of the values between the lowest and highest contained values that
+    /// are actually contained
+    ///
+    /// E.g. for `[1..=5, 10..=12]` that's 8 of the 12 values from 1 to 12, or `0.666...`.
+    /// A contiguous range (including a single value) has a density of `1.0`, and an
+    /// empty range has a density of `NaN`.
+    pub fn density(&self) -> f64 {
+        match (self.ranges.first(), self.ranges.last()) {
+            (Some(first), Some(last)) => {
+                let span = UnaryRange::new_unchecked(first.low, last.high).count();
+                self.count() as f64 / span as f64
+            }
+            _ => f64::NAN,
+        }
+    }
+
+    /// The `n`th contained value (counting from `0`), in ascending order
+    ///
+    /// Returns `None` if there are `n` or fewer contained values.
+    pub fn nth(&self, n: u128) -> Option<T> {
+        let mut remaining = n;
+        for range in self.ranges.iter() {
+            let count = range.count();
+            if remaining < count {
+                return Some(range.low.add_steps(remaining));
+            }
+            remaining -= count;
+        }
+        None
+    }
+
+    /// The contained value at fractional position `q` (from `0.0` to `1.0`) when they're
+    /// all lined up in ascending order, e.g. `0.5` for the median
+    ///
+    /// The position is rounded to the nearest value, so `0.0` is always the lowest value
+    /// and `1.0` the highest. Returns `None` if `q` is outside of `0.0..=1.0` (or `NaN`),
+    /// or if the range is empty. For very large ranges, the position is only as precise
+    /// as an `f64`.
+    pub fn quantile(&self, q: f64) -> Option<T> {
+        if !(0.0..=1.0).contains(&q) {
+            return None;
+        }
+        let last = self.count().checked_sub(1)?;
+        let position = (q * last as f64).round() as u128;
+        self.nth(position.min(last))
+    }
+
+    /// How many contained ranges there are of each size, keyed by [`UnaryRange::count`]
+    pub fn width_histogram(&self) -> BTreeMap<u128, usize> {
+        let mut histogram = BTreeMap::new();
+        for range in self.ranges.iter() {
+            *histogram.entry(range.count()).or_default() += 1;
+        }
+        histogram
+    }
+
+    /// The number of contained ranges
+    pub fn num_ranges(&self) -> usize {
+        self.ranges.len()
+    }
+
+    /// The contained ranges, sorted and melded
+    ///
+    /// See [`DisjointRange`] for the ordering guarantee.
+    pub fn as_slice(&self) -> &[UnaryRange<T>] {
+        &self.ranges
+    }
+
+    /// The contained range at `idx`, or `None` if `idx` is out of bounds
+    pub fn get(&self, idx: usize) -> Option<&UnaryRange<T>> {
+        self.ranges.get(idx)
+    }
+
+    /// Iterator over overlapping windows of `size` consecutive contained ranges
+    ///
+    /// See [`slice::windows`]. Like it, this panics if `size` is `0`.
+    pub fn range_windows(&self, size: usize) -> Windows<'_, UnaryRange<T>> {
+        self.ranges.windows(size)
+    }
+
+    /// Iterator over the contained ranges, each paired with the gap between it and
+    /// the next one
+    ///
+    /// The last range is paired with `None`. Any gap before the first range or after
+    /// the last one isn't included.
+    pub fn ranges_with_gaps(
+        &self,
+    ) -> impl Iterator<Item = (UnaryRange<T>, Option<UnaryRange<T>>)> + '_ {
+        self.ranges.iter().enumerate().map(|(idx, range)| {
+            let gap = self.ranges.get(idx + 1).and_then(|next| {
+                let low = self.mode.step_up(&range.high)?;
+                let high = self.mode.step_down(&next.low)?;
+                Some(UnaryRange::new_unchecked(low, high))
+            });
+            (*range, gap)
+        })
+    }
+
+    /// Iterator over the contained ranges
+    ///
+    /// The ranges work with the usual iterator combinators, e.g. for statistics
+    /// there isn't a dedicated method for:
+    ///
+    /// ```
+    /// use disjoint_ranges::DisjointRange;
+    ///
+    /// let range = DisjointRange::from_bounds_unchecked([(1u32, 5), (8, 8), (12, 20)]);
+    /// let covered: u128 = range.ranges_iter().map(|r| r.count()).sum();
+    /// assert_eq!(range.count(), covered);
+    /// let widest = range.ranges_iter().map(|r| r.count()).max();
+    /// assert_eq!(Some(9), widest);
+    /// ```
+    pub fn ranges_iter(&self) -> RangesIter<T> {
+        let ranges = VecDeque::from(self.ranges.clone());
+        RangesIter { ranges }
+    }
+
+    /// Iterator over the contained ranges as `(low, count)` pairs, in ascending order
+    ///
+    /// Handy for APIs that want a base and a length rather than a base and an end.
+    /// Each count is the [widened](UnaryRange::count) `u128` count, so it saturates at
+    /// `u128::MAX` rather than overflowing.
+    pub fn iter_offsets(&self) -> impl Iterator<Item = (T, u128)> + '_ {
+        self.ranges.iter().map(|range| (range.low, range.count()))
+    }
+
+    /// Iterator over at most `max` of the contained values, in ascending order
+    pub fn iter_values_bounded(&self, max: usize) -> impl Iterator<Item = T> + '_ {
+        self.ranges
+            .iter()
+            .copied()
+            .flat_map(UnaryRange::into_iter)
+            .take(max)
+    }
+
+    /// Collect every contained value, or `None` if there are more than `max` of them
+    ///
+    /// The count is checked up front, so a huge set is rejected without allocating.
+    pub fn try_to_values(&self, max: usize) -> Option<Vec<T>> {
+        let count = usize::try_from(self.checked_count()?).ok()?;
+        (count <= max).then(|| self.iter_values_bounded(count).collect())
+    }
+}
+
+impl<T> DisjointRange<T>
+where
+    T: Copy + Clone + Ord + Stepped + Default,
+{
+    /// Fill in every gap between ranges containing `max_gap` or fewer values,
+    /// merging the ranges on either side
+    ///
+    /// Like [`DisjointRange::count_t`], `max_gap` is a count expressed as a `T`, i.e. the
+    /// number of [steps](Stepped::STEP) from `T::default()` (zero) up to it. A `max_gap`
+    /// below zero fills nothing.
+    pub fn fill_gaps_smaller_than(&mut self, max_gap: T) {
+        let Some(max_gap) = T::default().steps_between(&max_gap) else {
+            return;
+        };
+        let mode = self.mode;
+        let mut filled: Vec<UnaryRange<T>> = Vec::with_capacity(self.ranges.len());
+        for range in self.ranges.drain(..) {
+            match filled.last_mut() {
+                // the gap runs from the step after `last` up to (but not including) `range`
+                Some(last)
+                    if mode.step_up(&last.high).is_none_or(|after| {
+                        after
+                            .steps_between(&range.low)
+                            .is_none_or(|gap| gap <= max_gap)
+                    }) =>
+                {
+                    last.high = max(last.high, range.high);
+                }
+                _ => filled.push(range),
+            }
+        }
+        self.ranges = filled;
+    }
+}
+
+impl<T> DisjointRange<T>
+where
+    T: Copy + Clone + Ord + Bounded + Stepped,
+{
+    /// Create a range that covers all values
+    ///
+    /// In debug builds, this also [validates](Stepped::validate) `T`'s [`Stepped`] impl.
+    pub fn entire() -> Self {
+        T::validate();
+        Self::new_single_range_unchecked(bounded_min(), bounded_max())
+    }
+
+    /// Test whether the range covers all values, i.e. whether it's equivalent to
+    /// [`DisjointRange::entire`]
+    pub fn is_entire(&self) -> bool {
+        matches!(
+            self.ranges.as_slice(),
+            [range] if range.low == bounded_min() && range.high == bounded_max()
+        )
+    }
+
+    /// Test whether every value described by `bounds` is contained
+    ///
+    /// `bounds` can be any std range (`a..b`, `a..=b`, `a..`, `..`, etc.), with unbounded
+    /// ends standing for [`Bounded::MIN_VAL`] and [`Bounded::MAX_VAL`]. Bounds describing
+    /// no values at all (e.g. `5..5`) are trivially contained.
+    pub fn contains_bounds<R: RangeBounds<T>>(&self, bounds: R) -> bool {
+        let low = match bounds.start_bound() {
+            Bound::Included(low) => Some(*low),
+            Bound::Excluded(low) => low.checked_increment(),
+            Bound::Unbounded => Some(bounded_min()),
+        };
+        let high = match bounds.end_bound() {
+            Bound::Included(high) => Some(*high),
+            Bound::Excluded(high) => high.checked_decrement(),
+            Bound::Unbounded => Some(bounded_max()),
+        };
+        match (low, high) {
+            (Some(low), Some(high)) if low <= high => self
+                .range_containing(&low)
+                .is_some_and(|range| high <= range.high),
+            _ => true,
+        }
+    }
+
+    /// The complement (or "inverse") of this range
+    ///
+    /// This is every gap between the contained ranges, plus the values before the first
+    /// one and after the last one, found in a single linear sweep. The storage of `self`
+    /// is reused (see [`DisjointRange::complement_in_place`]).
+    pub fn complement(mut self) -> Self {
+        self.complement_in_place();
+        self
+    }
+
+    /// The complement (or "inverse") of this range, without consuming it
+    ///
+    /// See [`DisjointRange::complement`]
+    pub fn complement_ref(&self) -> Self {
+        let mut ranges = Vec::with_capacity(self.ranges.len() + 1);
+        ranges.extend(self.iter_complement());
+        self.with_ranges(ranges)
+    }
+
+    /// Iterator over the ranges making up the complement of this range, without
+    /// building a new `DisjointRange`
+    ///
+    /// Yields the same ranges as [`DisjointRange::complement_ref`], in ascending order.
+    /// Each one is just the gap between consecutive contained ranges, or between
+    /// [`Bounded::MIN_VAL`]/[`Bounded::MAX_VAL`] and the first/last one.
+    pub fn iter_complement(&self) -> impl Iterator<Item = UnaryRange<T>> + '_ {
+        T::validate();
+        let leading = match self.ranges.first() {
+            Some(first) => self
+                .mode
+                .step_down(&first.low)
+                .map(|high| UnaryRange::new_unchecked(bounded_min(), high)),
+            None => Some(UnaryRange::new_unchecked(bounded_min(), bounded_max())),
+        };
+        let gaps = self.ranges.windows(2).map(|pair| {
+            UnaryRange::new_unchecked(pair[0].high.increment(), pair[1].low.decrement())
+        });
+        let trailing = self.ranges.last().and_then(|last| {
+            self.mode
+                .step_up(&last.high)
+                .map(|low| UnaryRange::new_unchecked(low, bounded_max()))
+        });
+        leading.into_iter().chain(gaps).chain(trailing)
+    }
+
+    /// Replace this range with its complement, reusing its storage
+    ///
+    /// See [`DisjointRange::complement`]
+    pub fn complement_in_place(&mut self) {
+        T::validate();
+        let (Some(first), Some(last)) = (self.ranges.first().copied(), self.ranges.last().copied())
+        else {
+            self.ranges
+                .push(UnaryRange::new_unchecked(bounded_min(), bounded_max()));
+            return;
+        };
+        // each range is overwritten by the gap after it, which only depends on it and
+        // the (not yet overwritten) range after that
+        for idx in 0..self.ranges.len() - 1 {
+            let gap_low = self.ranges[idx].high.increment();
+            let gap_high = self.ranges[idx + 1].low.decrement();
+            self.ranges[idx] = UnaryRange::new_unchecked(gap_low, gap_high);
+        }
+        match self.mode.step_up(&last.high) {
+            Some(low) => {
+                *self.ranges.last_mut().unwrap() = UnaryRange::new_unchecked(low, bounded_max())
+            }
+            None => {
+                self.ranges.pop();
+            }
+        }
+        if let Some(high) = self.mode.step_down(&first.low) {
+            self.ranges
+                .insert(0, UnaryRange::new_unchecked(bounded_min(), high));
+        }
+    }
+
+    /// The values in `domain` that aren't in this range
+    ///
+    /// This is the complement relative to `domain` rather than to every value of `T`,
+    /// e.g. for a `u16` where only `0..=999` are meaningful. `domain` can have gaps too.
+    /// See [`UnaryRange::complement_within`] for a contiguous `domain`.
+    pub fn complement_in(&self, domain: &DisjointRange<T>) -> Self {
+        let mut out = domain.clone();
+        out.difference_with(self);
+        out
+    }
+}
+
+/// Access the contained ranges by position
+///
+/// Like indexing a slice, this panics if the index is out of bounds. See
+/// [`DisjointRange::get`] for a non-panicking alternative.
+impl<T> Index<usize> for DisjointRange<T> {
+    type Output = UnaryRange<T>;
+
+    fn index(&self, idx: usize) -> &Self::Output {
+        &self.ranges[idx]
+    }
+}
+
+/// Equal when the `DisjointRange` is exactly the one [`UnaryRange`]
+impl<T: PartialEq> PartialEq<UnaryRange<T>> for DisjointRange<T> {
+    fn eq(&self, other: &UnaryRange<T>) -> bool {
+        matches!(self.ranges.as_slice(), [range] if range == other)
+    }
+}
+
+/// Equal when the [`DisjointRange`] is exactly this one `UnaryRange`
+impl<T: PartialEq> PartialEq<DisjointRange<T>> for UnaryRange<T> {
+    fn eq(&self, other: &DisjointRange<T>) -> bool {
+        other == self
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<T> DisjointRange<T>
+where
+    T: Copy + Clone + Ord + Bounded + Stepped + Send + Sync,
+{
+    /// Intersect all of `sets` in parallel, using [`rayon`]
+    ///
+    /// Since intersection is associative, `sets` are reduced pairwise in a tree rather than
+    /// one after another, and the result is the same either way. Intersecting no sets at all
+    /// results in [`DisjointRange::entire`].
+    pub fn par_intersect_all<I>(sets: I) -> Self
+    where
+        I: IntoIterator<Item = DisjointRange<T>>,
+    {
+        use rayon::prelude::*;
+
+        sets.into_iter()
+            .collect::<Vec<Self>>()
+            .into_par_iter()
+            .reduce(Self::entire, |mut acc, set| {
+                acc.intersect_with(&set);
+                acc
+            })
+    }
+}
+
+impl<T> DisjointRange<T>
+where
+    T: Copy
+        + Clone
+        + Ord
+        + Bounded
+        + Stepped
+        + Default
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Rem<Output = T>,
+{
+    /// Grow every range outwards to whole blocks of `stride` values, melding any that
+    /// end up overlapping
+    ///
+    /// Each `low` is rounded down to a multiple of `stride`, and each `high` is rounded
+    /// up to one less than a multiple of `stride` (the last value of its block), so with
+    /// a `stride` of `4096`, `10..=5000` becomes `0..=8191`. Rounding towards negative
+    /// infinity, `-5..=5` with a `stride` of `4` becomes `-8..=7`.
+    ///
+    /// A block that would extend past [`Bounded::MIN_VAL`] or [`Bounded::MAX_VAL`] is
+    /// clamped to it, so e.g. a `u8` range with a `stride` of `100` never grows past
+    /// `255` even though the block containing it ends at `299`.
+    ///
+    /// Panics if `stride` isn't positive, i.e. greater than `T::default()`.
+    pub fn align_to(&self, stride: T) -> Self {
+        let zero = T::default();
+        assert!(stride > zero, "stride must be positive");
+        // `%` truncates towards zero, but the distance to the block start can't be negative
+        let offset = |val: T| {
+            let rem = val % stride;
+            if rem < zero { rem + stride } else { rem }
+        };
+        let mut ranges: Vec<UnaryRange<T>> = self
+            .ranges
+            .iter()
+            .map(|range| {
+                let below = offset(range.low);
+                let low = if range.low < bounded_min::<T>() + below {
+                    bounded_min()
+                } else {
+                    range.low - below
+                };
+                let above = stride.decrement() - offset(range.high);
+                let high = if range.high > bounded_max::<T>() - above {
+                    bounded_max()
+                } else {
+                    range.high + above
+                };
+                UnaryRange::new_unchecked(low, high)
+            })
+            .collect();
+        DisjointRange::meld_with_mode_unchecked(&mut ranges, self.mode);
+        self.with_ranges(ranges)
+    }
+}
+
+impl<T> DisjointRange<T>
+where
+    T: Copy + Bounded + Default + Add<Output = T> + Sub<Output = T>,
+{
+    /// Move every value by `delta`, or `None` if any of them would end up outside
+    /// [`Bounded::MIN_VAL`]..=[`Bounded::MAX_VAL`]
+    ///
+    /// A negative `delta` (less than `T::default()`) shifts downwards. The gaps between
+    /// ranges are preserved exactly.
+    pub fn checked_shift(&self, delta: T) -> Option<Self> {
+        let fits = if delta >= T::default() {
+            self.ranges
+                .last()
+                .is_none_or(|last| last.high <= bounded_max::<T>() - delta)
+        } else {
+            self.ranges
+                .first()
+                .is_none_or(|first| first.low >= bounded_min::<T>() - delta)
+        };
+        fits.then(|| Self {
+            ranges: self
+                .ranges
+                .iter()
+                .map(|range| UnaryRange::new_unchecked(range.low + delta, range.high + delta))
+                .collect(),
+            mode: self.mode,
+        })
+    }
+}
+
+impl<T> DisjointRange<T>
+where
+    T: Copy + Clone + Ord + Bounded + Stepped + Default,
+{
+    /// The number of values in the range, expressed as a `T`, or `None` if that's more
+    /// than [`Bounded::MAX_VAL`]
+    ///
+    /// The count is stepped up from `T::default()` (i.e. zero), so e.g. a full
+    /// `DisjointRange<u32>` has `u32::MAX as u128 + 1` values and returns `None`.
+    pub fn count_t(&self) -> Option<T> {
+        let count = self.checked_count()?;
+        let zero = T::default();
+        (count <= zero.steps_between(&bounded_max())?).then(|| zero.add_steps(count))
+    }
+}
+
+impl DisjointRange<Ipv4Addr> {
+    /// Parse a CIDR block (e.g. `10.0.0.0/8`) into the addresses it covers
+    ///
+    /// See [`UnaryRange::from_cidr_v4`]
+    pub fn from_cidr_v4(cidr: &str) -> Result<Self, RangeError> {
+        UnaryRange::from_cidr_v4(cidr).map(|range| Self {
+            ranges: vec![range],
+            mode: StepMode::Saturating,
+        })
+    }
+
+    /// Decompose into the fewest CIDR blocks covering exactly these addresses, as
+    /// `(network address, prefix length)` pairs in ascending order
+    ///
+    /// See [`UnaryRange::to_cidrs`]
+    pub fn to_cidrs(&self) -> Vec<(Ipv4Addr, u8)> {
+        self.ranges.iter().flat_map(UnaryRange::to_cidrs).collect()
+    }
+}
+
+/// Add [`UnaryRange`]s, sorting and melding once they've all been added
+impl<T> Extend<UnaryRange<T>> for DisjointRange<T>
+where
+    T: Copy + Clone + Ord + Stepped,
+{
+    fn extend<I: IntoIterator<Item = UnaryRange<T>>>(&mut self, iter: I) {
+        self.ranges.extend(iter);
+        DisjointRange::meld_ranges_with_mode(&mut self.ranges, self.mode);
+    }
+}
+
+/// Add individual values, sorting and melding once they've all been added
+impl<T> Extend<T> for DisjointRange<T>
+where
+    T: Copy + Clone + Ord + Stepped,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.extend(
+            iter.into_iter()
+                .map(|val| UnaryRange::new_unchecked(val, val)),
+        );
+    }
+}
+
+pub struct RangesIter<T> {
+    ranges: std::collections::VecDeque<UnaryRange<T>>,
+}
+
+impl<T> Iterator for RangesIter<T> {
+    type Item = UnaryRange<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.ranges.pop_front()
+    }
+}
+
+/// Iterator adaptor melding a stream of [`UnaryRange`]s on the fly
+///
+/// This is the streaming counterpart of [`DisjointRange::meld_ranges_unchecked`]: the input
+/// must already be sorted by `low`, but it never has to be collected. Overlapping and
+/// adjacent ranges are combined, so the output is sorted and melded.
+///
+/// ```
+/// use disjoint_ranges::UnaryRange;
+/// use disjoint_ranges::ranges::MeldIter;
+///
+/// let sorted = [(0u8, 5), (3, 8), (9, 10), (20, 25)].map(|(low, high)| UnaryRange::new_unchecked(low, high));
+/// let melded: Vec<(u8, u8)> = MeldIter::new(sorted).map(|range| range.as_bounds()).collect();
+/// assert_eq!(vec![(0, 10), (20, 25)], melded);
+/// ```
+pub struct MeldIter<I: Iterator> {
+    iter: Peekable<I>,
+}
+
+impl<I: Iterator> MeldIter<I> {
+    /// Meld the ranges of `iter`, which must be sorted by `low`
+    pub fn new<R: IntoIterator<IntoIter = I>>(iter: R) -> Self {
+        MeldIter {
+            iter: iter.into_iter().peekable(),
+        }
+    }
+}
+
+impl<T, I> Iterator for MeldIter<I>
+where
+    T: Copy + Clone + Ord + Stepped,
+    I: Iterator<Item = UnaryRange<T>>,
+{
+    type Item = UnaryRange<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut current = self.iter.next()?;
+        while let Some(next) = self.iter.next_if(|next| {
+            current
+                .high
+                .checked_increment()
+                .is_none_or(|after| next.low <= after)
+        }) {
+            current.high = max(current.high, next.high);
+        }
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (low, high) = self.iter.size_hint();
+        (low.min(1), high)
+    }
+}
+
+/// Which of two [`DisjointRange`]s a segment of [`DisjointRange::overlay`] came from
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Membership {
+    /// Only in `self`
+    OnlyLeft,
+    /// Only in `other`
+    OnlyRight,
+    /// In both
+    Both,
+}
+
+/// Iterator over the values in a [`UnaryRange`], from `low` to `high` (inclusive)
+///
+/// Like [`std::ops::RangeInclusive`], this is only an [`ExactSizeIterator`] for types
+/// whose full domain can be counted with a `usize` on every platform (`u8`, `i8`,
+/// `u16`, `i16`). Iterators over other types still work and still report an accurate
+/// [`Iterator::size_hint`] when the remaining count fits in a `usize`.
+pub struct ValuesIter<T> {
+    next: T,
+    high: T,
+    done: bool,
+}
+
+impl<T> Iterator for ValuesIter<T>
+where
+    T: Copy + Clone + Stepped,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let val = self.next;
+        let next = val.increment();
+        // stop once stepping stops moving (e.g. saturating at `T::MAX_VAL`, or a float
+        // too big for `EPSILON` to change) as well as once it passes `high`
+        if next <= val || next > self.high {
+            self.done = true;
+        } else {
+            self.next = next;
+        }
+        Some(val)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        match self
+            .next
+            .steps_between(&self.high)
+            .and_then(|steps| steps.checked_add(1))
+            .and_then(|remaining| usize::try_from(remaining).ok())
+        {
+            Some(remaining) => (remaining, Some(remaining)),
+            None => (usize::MAX, None),
+        }
+    }
+}
+
+impl ExactSizeIterator for ValuesIter<u8> {}
+impl ExactSizeIterator for ValuesIter<i8> {}
+impl ExactSizeIterator for ValuesIter<u16> {}
+impl ExactSizeIterator for ValuesIter<i16> {}
+
+impl<T> IntoIterator for UnaryRange<T>
+where
+    T: Copy + Clone + Stepped,
+{
+    type Item = T;
+    type IntoIter = ValuesIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        ValuesIter {
+            next: self.low,
+            high: self.high,
+            done: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DisjointRange, StepMode, UnaryRange};
+    use crate::traits::{Bounded, Stepped};
+    #[test]
+    fn test_without_lower() {
+        let range = UnaryRange::new_unchecked(5, 10);
+        let wo_range = UnaryRange::new_unchecked(3, 6);
+        let actual = range.without(wo_range).unwrap();
+        assert_eq!(1, actual.len());
+        assert_eq!(7, actual[0].low);
+        assert_eq!(10, actual[0].high);
+    }
+    #[test]
+    fn test_without_higher() {
+        let range = UnaryRange::new_unchecked(5, 10);
+        let wo_range = UnaryRange::new_unchecked(8, 12);
+        let actual = range.without(wo_range).unwrap();
+        assert_eq!(1, actual.len());
+        assert_eq!(5, actual[0].low);
+        assert_eq!(7, actual[0].high);
+    }
+    #[test]
+    fn test_without_middle() {
+        let range = UnaryRange::new_unchecked(5, 10);
+        let wo_range = UnaryRange::new_unchecked(7, 8);
+        let actual = range.without(wo_range).unwrap();
+        assert_eq!(2, actual.len());
+        let actual_smaller = actual[0];
+        let actual_greater = actual[1];
+        assert_eq!(5, actual_smaller.low);
+        assert_eq!(6, actual_smaller.high);
+        assert_eq!(9, actual_greater.low);
+        assert_eq!(10, actual_greater.high);
+    }
+    #[test]
+    fn test_without_single_value_remaining() {
+        let range = UnaryRange::new_unchecked(5, 10);
+        let actual = range.without(UnaryRange::new_unchecked(6, 12)).unwrap();
+        assert_eq!(vec![UnaryRange { low: 5, high: 5 }], actual);
+        let actual = range.without(UnaryRange::new_unchecked(3, 9)).unwrap();
+        assert_eq!(vec![UnaryRange { low: 10, high: 10 }], actual);
+    }
+    #[test]
+    fn test_without_covering() {
+        let range = UnaryRange::new_unchecked(0u8, 10);
+        assert!(range.without(UnaryRange::new_unchecked(0, 10)).is_none());
+        assert!(
+            range
+                .without(UnaryRange::new_unchecked(0, u8::MAX))
+                .is_none()
+        );
+    }
+    #[test]
+    fn test_meld_ranges_overlapping() {
+        let mut ranges = vec![
+            UnaryRange::new_unchecked(0, 9),
+            UnaryRange::new_unchecked(10, 19),
+        ];
+        DisjointRange::meld_ranges_unchecked(&mut ranges);
+        assert_eq!(1, ranges.len());
+        let UnaryRange { low, high } = ranges[0];
+        assert_eq!(0, low);
+        assert_eq!(19, high);
+    }
+
+    #[test]
+    fn test_meld_ranges_non_overlapping() {
+        let mut ranges = vec![
+            UnaryRange::new_unchecked(0, 9),
+            UnaryRange::new_unchecked(11, 19),
+        ];
+        DisjointRange::meld_ranges_unchecked(&mut ranges);
+        assert_eq!(2, ranges.len());
+        let UnaryRange { low, high } = ranges[0];
+        assert_eq!(0, low);
+        assert_eq!(9, high);
+        let UnaryRange { low, high } = ranges[1];
+        assert_eq!(11, low);
+        assert_eq!(19, high);
+    }
+    #[test]
+    fn test_meld_ranges_first_two() {
+        let mut ranges = vec![
+            UnaryRange::new_unchecked(0, 4),
+            UnaryRange::new_unchecked(5, 9),
+            UnaryRange::new_unchecked(11, 15),
+        ];
+        DisjointRange::meld_ranges_unchecked(&mut ranges);
+        assert_eq!(2, ranges.len());
+        let UnaryRange { low, high } = ranges[0];
+        assert_eq!(0, low);
+        assert_eq!(9, high);
+        let UnaryRange { low, high } = ranges[1];
+        assert_eq!(11, low);
+        assert_eq!(15, high);
+    }
+    #[test]
+    fn test_meld_ranges_last_two() {
+        let mut ranges = vec![
+            UnaryRange::new_unchecked(0, 4),
+            UnaryRange::new_unchecked(6, 10),
+            UnaryRange::new_unchecked(11, 15),
+        ];
+        DisjointRange::meld_ranges_unchecked(&mut ranges);
+        assert_eq!(2, ranges.len());
+        let UnaryRange { low, high } = ranges[0];
+        assert_eq!(0, low);
+        assert_eq!(4, high);
+        let UnaryRange { low, high } = ranges[1];
+        assert_eq!(6, low);
+        assert_eq!(15, high);
+    }
+    #[test]
+    fn test_meld_ranges_all() {
+        let mut ranges = vec![
+            UnaryRange::new_unchecked(0, 4),
+            UnaryRange::new_unchecked(5, 10),
+            UnaryRange::new_unchecked(11, 15),
+        ];
+        DisjointRange::meld_ranges_unchecked(&mut ranges);
+        assert_eq!(1, ranges.len());
+        let UnaryRange { low, high } = ranges[0];
+        assert_eq!(0, low);
+        assert_eq!(15, high);
+    }
+    #[test]
+    fn test_subtract_range_lowest_lower() {
+        let mut orig = DisjointRange::from_bounds_unchecked(vec![(0, 4), (6, 10), (12, 16)]);
+        let o2 = orig.clone();
+        let wo_range = UnaryRange::new_unchecked(0, 2);
+        orig.subtract_unary_range(wo_range);
+        assert_eq!(3, orig.ranges.len());
+        assert_eq!(UnaryRange { low: 3, high: 4 }, orig.ranges[0]);
+        assert_eq!(o2.ranges[1], orig.ranges[1]);
+        assert_eq!(o2.ranges[2], orig.ranges[2]);
+    }
+    #[test]
+    fn test_subtract_unary_range_lowest_higher() {
+        let mut orig = DisjointRange::from_bounds_unchecked(vec![(0, 4), (6, 10), (12, 16)]);
+        let o2 = orig.clone();
+        let wo_range = UnaryRange::new_unchecked(2, 4);
+        orig.subtract_unary_range(wo_range);
+        assert_eq!(3, orig.ranges.len());
+        assert_eq!(UnaryRange { low: 0, high: 1 }, orig.ranges[0]);
+        assert_eq!(o2.ranges[1], orig.ranges[1]);
+        assert_eq!(o2.ranges[2], orig.ranges[2]);
+    }
+    #[test]
+    fn test_subtract_unary_range_lowest_middle() {
+        let mut orig = DisjointRange::from_bounds_unchecked(vec![(0, 4), (6, 10), (12, 16)]);
+        let o2 = orig.clone();
+        let wo_range = UnaryRange::new_unchecked(2, 3);
+        orig.subtract_unary_range(wo_range);
+        assert_eq!(4, orig.ranges.len());
+        assert_eq!(UnaryRange { low: 0, high: 1 }, orig.ranges[0]);
+        assert_eq!(UnaryRange { low: 4, high: 4 }, orig.ranges[1]);
+        assert_eq!(o2.ranges[1], orig.ranges[2]);
+        assert_eq!(o2.ranges[2], orig.ranges[3]);
+    }
+    #[test]
+    fn test_subtract_unary_range_lower_spanning() {
+        let mut orig = DisjointRange::from_bounds_unchecked(vec![(0, 4), (6, 10), (12, 16)]);
+        let o2 = orig.clone();
+        let wo_range = UnaryRange::new_unchecked(4, 7);
+        orig.subtract_unary_range(wo_range);
+        assert_eq!(3, orig.ranges.len());
+        assert_eq!(UnaryRange { low: 0, high: 3 }, orig.ranges[0]);
+        assert_eq!(UnaryRange { low: 8, high: 10 }, orig.ranges[1]);
+        assert_eq!(o2.ranges[2], orig.ranges[2]);
+    }
+    #[test]
+    fn test_subtract_unary_range_spanning_all() {
+        let mut orig = DisjointRange::from_bounds_unchecked(vec![(0, 4), (6, 10), (12, 16)]);
+        orig.subtract_unary_range(UnaryRange::new_unchecked(0, 16));
+        assert!(orig.ranges.is_empty());
+        let mut orig = DisjointRange::from_bounds_unchecked(vec![(0, 4), (6, 10), (12, 16)]);
+        orig.subtract_unary_range(UnaryRange::new_unchecked(3, 13));
+        assert_eq!(
+            vec![
+                UnaryRange { low: 0, high: 2 },
+                UnaryRange { low: 14, high: 16 }
+            ],
+            orig.ranges
+        );
+    }
+    #[test]
+    fn test_add_unary_range_before_separate() {
+        let mut orig = DisjointRange::from_bounds_unchecked(vec![(4, 6), (8, 10)]);
+        let o2 = orig.clone();
+        let to_add = UnaryRange::new_unchecked(0, 2);
+        let ta = to_add;
+        orig.add_unary_range(to_add);
+        assert_eq!(3, orig.ranges.len());
+        assert_eq!(ta, orig.ranges[0]);
+        assert_eq!(o2.ranges[0], orig.ranges[1]);
+        assert_eq!(o2.ranges[1], orig.ranges[2]);
+    }
+    #[test]
+    fn test_add_unary_range_before_merged() {
+        let mut orig = DisjointRange::from_bounds_unchecked(vec![(4, 6), (8, 10)]);
+        let o2 = orig.clone();
+        let to_add = UnaryRange::new_unchecked(0, 3);
+        orig.add_unary_range(to_add);
+        assert_eq!(2, orig.ranges.len());
+        assert_eq!(UnaryRange { low: 0, high: 6 }, orig.ranges[0]);
+        assert_eq!(o2.ranges[1], orig.ranges[1]);
+    }
+    #[test]
+    fn test_add_unary_range_middle_separate() {
+        let mut orig = DisjointRange::from_bounds_unchecked(vec![(4, 5), (10, 11)]);
+        let o2 = orig.clone();
+        let to_add = UnaryRange::new_unchecked(7, 8);
+        let ta = to_add;
+        orig.add_unary_range(to_add);
+        assert_eq!(3, orig.ranges.len());
+        assert_eq!(o2.ranges[0], orig.ranges[0]);
+        assert_eq!(ta, orig.ranges[1]);
+        assert_eq!(o2.ranges[1], orig.ranges[2]);
+    }
+    #[test]
+    fn test_add_unary_range_middle_merged() {
+        let mut orig = DisjointRange::from_bounds_unchecked(vec![(4, 5), (10, 11)]);
+        let o2 = orig.clone();
+        let to_add = UnaryRange::new_unchecked(6, 8);
+        orig.add_unary_range(to_add);
+        assert_eq!(2, orig.ranges.len());
+        assert_eq!(UnaryRange { low: 4, high: 8 }, orig.ranges[0]);
+        assert_eq!(o2.ranges[1], orig.ranges[1]);
+    }
+    #[test]
+    fn test_add_unary_range_merge_all() {
+        let mut orig = DisjointRange::from_bounds_unchecked(vec![(4, 5), (10, 11)]);
+        let to_add = UnaryRange::new_unchecked(6, 9);
+        orig.add_unary_range(to_add);
+        assert_eq!(1, orig.ranges.len());
+        assert_eq!(UnaryRange { low: 4, high: 11 }, orig.ranges[0]);
+    }
+    #[test]
+    fn test_complement_unary() {
+        let orig = UnaryRange::new_unchecked(10u8, 50u8);
+        let complement = orig.complement().unwrap();
+        assert_eq!(2, complement.ranges.len());
+        assert_eq!(UnaryRange { low: 0, high: 9 }, complement.ranges[0]);
+        assert_eq!(
+            UnaryRange {
+                low: 51,
+                high: u8::MAX
+            },
+            complement.ranges[1]
+        );
+    }
+    #[test]
+    fn test_complement_disjoint() {
+        let orig = DisjointRange::from_bounds_unchecked([(10u8, 50), (70, 100)]);
+        let complement = orig.complement();
+        assert_eq!(3, complement.ranges.len());
+        assert_eq!(UnaryRange { low: 0, high: 9 }, complement.ranges[0]);
+        assert_eq!(UnaryRange { low: 51, high: 69 }, complement.ranges[1]);
+        assert_eq!(
+            UnaryRange {
+                low: 101,
+                high: u8::MAX
+            },
+            complement.ranges[2]
+        );
+    }
+    #[test]
+    fn test_reserve_shrink_to_fit() {
+        let mut orig = DisjointRange::from_bounds_unchecked([(0u8, 4), (6, 10)]);
+        orig.reserve(100);
+        assert!(orig.ranges.capacity() >= 102);
+        orig.shrink_to_fit();
+        assert_eq!(2, orig.ranges.capacity());
+        assert_eq!(UnaryRange { low: 0, high: 4 }, orig.ranges[0]);
+        assert_eq!(UnaryRange { low: 6, high: 10 }, orig.ranges[1]);
+    }
+    #[test]
+    fn test_unary_range_into_iter() {
+        let values: Vec<u8> = UnaryRange::new_unchecked(3, 6).into_iter().collect();
+        assert_eq!(vec![3, 4, 5, 6], values);
+        let values: Vec<u8> = UnaryRange::new_unchecked(7, 7).into_iter().collect();
+        assert_eq!(vec![7], values);
+    }
+    #[test]
+    fn test_unary_range_into_iter_max() {
+        let values: Vec<u8> = UnaryRange::new_unchecked(253, u8::MAX)
+            .into_iter()
+            .collect();
+        assert_eq!(vec![253, 254, 255], values);
+        let values: Vec<i8> = UnaryRange::new_unchecked(i8::MIN, i8::MAX)
+            .into_iter()
+            .collect();
+        assert_eq!(256, values.len());
+        assert_eq!(Some(&i8::MAX), values.last());
+    }
+    #[test]
+    fn test_unary_range_into_iter_stuck() {
+        let values: Vec<f64> = UnaryRange::new_unchecked(1e16, 1e16 + 4.0)
+            .into_iter()
+            .collect();
+        assert_eq!(vec![1e16], values);
+        let values: Vec<f64> = UnaryRange::new_unchecked(f64::MAX, f64::MAX)
+            .into_iter()
+            .collect();
+        assert_eq!(vec![f64::MAX], values);
+    }
+    #[test]
+    fn test_unary_range_values_len() {
+        let mut values = UnaryRange::new_unchecked(3u8, 6).into_iter();
+        assert_eq!(4, values.len());
+        values.next();
+        assert_eq!(3, values.len());
+        values.by_ref().for_each(drop);
+        assert_eq!(0, values.len());
+        let values = UnaryRange::new_unchecked(i16::MIN, i16::MAX).into_iter();
+        assert_eq!(65536, values.len());
+    }
+    #[test]
+    fn test_unary_range_values_size_hint() {
+        let values = UnaryRange::new_unchecked(10u64, 19).into_iter();
+        assert_eq!((10, Some(10)), values.size_hint());
+        let values = UnaryRange::new_unchecked(u128::MIN, u128::MAX).into_iter();
+        assert_eq!((usize::MAX, None), values.size_hint());
+    }
+    #[test]
+    fn test_overlap_len() {
+        let range = UnaryRange::new_unchecked(5u8, 10);
+        assert_eq!(6, range.overlap_len(&range));
+        assert_eq!(3, range.overlap_len(&UnaryRange::new_unchecked(0, 7)));
+        assert_eq!(2, range.overlap_len(&UnaryRange::new_unchecked(9, 20)));
+        assert_eq!(2, range.overlap_len(&UnaryRange::new_unchecked(7, 8)));
+        assert_eq!(1, range.overlap_len(&UnaryRange::new_unchecked(10, 20)));
+        assert_eq!(0, range.overlap_len(&UnaryRange::new_unchecked(11, 20)));
+        assert_eq!(0, range.overlap_len(&UnaryRange::new_unchecked(0, 4)));
+    }
+    #[test]
+    fn test_overlap_len_saturates() {
+        let range = UnaryRange::new_unchecked(u128::MIN, u128::MAX);
+        assert_eq!(u128::MAX, range.overlap_len(&range));
+    }
+    #[test]
+    fn test_partition_straddling() {
+        let orig = DisjointRange::from_bounds_unchecked([(0u8, 4), (6, 10), (12, 16)]);
+        let (lower, upper) = orig.partition(8);
+        assert_eq!(
+            vec![
+                UnaryRange { low: 0, high: 4 },
+                UnaryRange { low: 6, high: 8 }
+            ],
+            lower.ranges
+        );
+        assert_eq!(
+            vec![
+                UnaryRange { low: 9, high: 10 },
+                UnaryRange { low: 12, high: 16 }
+            ],
+            upper.ranges
+        );
+    }
+    #[test]
+    fn test_partition_boundaries() {
+        let orig = DisjointRange::from_bounds_unchecked([(0u8, 4), (6, 10)]);
+        let (lower, upper) = orig.partition(10);
+        assert_eq!(orig.ranges, lower.ranges);
+        assert!(upper.ranges.is_empty());
+        let (lower, upper) = orig.partition(5);
+        assert_eq!(vec![UnaryRange { low: 0, high: 4 }], lower.ranges);
+        assert_eq!(vec![UnaryRange { low: 6, high: 10 }], upper.ranges);
+        let (lower, upper) = orig.partition(6);
+        assert_eq!(
+            vec![
+                UnaryRange { low: 0, high: 4 },
+                UnaryRange { low: 6, high: 6 }
+            ],
+            lower.ranges
+        );
+        assert_eq!(vec![UnaryRange { low: 7, high: 10 }], upper.ranges);
+        let (lower, upper) = DisjointRange::<u8>::entire().partition(u8::MAX);
+        assert_eq!(DisjointRange::<u8>::entire().ranges, lower.ranges);
+        assert!(upper.ranges.is_empty());
+    }
+    #[test]
+    fn test_drain_window() {
+        let mut orig = DisjointRange::from_bounds_unchecked([(0u8, 4), (6, 10), (12, 16)]);
+        let drained = orig.drain_window(UnaryRange::new_unchecked(3, 13));
+        assert_eq!(
+            vec![
+                UnaryRange { low: 3, high: 4 },
+                UnaryRange { low: 6, high: 10 },
+                UnaryRange { low: 12, high: 13 }
+            ],
+            drained.ranges
+        );
+        assert_eq!(
+            vec![
+                UnaryRange { low: 0, high: 2 },
+                UnaryRange { low: 14, high: 16 }
+            ],
+            orig.ranges
+        );
+    }
+    #[test]
+    fn test_drain_window_inside_range() {
+        let mut orig = DisjointRange::from_bounds_unchecked([(0u8, 20)]);
+        let drained = orig.drain_window(UnaryRange::new_unchecked(5, 10));
+        assert_eq!(vec![UnaryRange { low: 5, high: 10 }], drained.ranges);
+        assert_eq!(
+            vec![
+                UnaryRange { low: 0, high: 4 },
+                UnaryRange { low: 11, high: 20 }
+            ],
+            orig.ranges
+        );
+    }
+    #[test]
+    fn test_drain_window_gap() {
+        let mut orig = DisjointRange::from_bounds_unchecked([(0u8, 4), (10, 20)]);
+        let drained = orig.drain_window(UnaryRange::new_unchecked(5, 9));
+        assert!(drained.ranges.is_empty());
+        assert_eq!(2, orig.ranges.len());
+    }
+    #[test]
+    fn test_split_off_straddling() {
+        let mut orig = DisjointRange::from_bounds_unchecked([(0u8, 4), (6, 10), (12, 16)]);
+        let upper = orig.split_off(8);
+        assert_eq!(
+            vec![
+                UnaryRange { low: 0, high: 4 },
+                UnaryRange { low: 6, high: 7 }
+            ],
+            orig.ranges
+        );
+        assert_eq!(
+            vec![
+                UnaryRange { low: 8, high: 10 },
+                UnaryRange { low: 12, high: 16 }
+            ],
+            upper.ranges
+        );
+    }
+    #[test]
+    fn test_split_off_boundaries() {
+        let mut orig = DisjointRange::from_bounds_unchecked([(0u8, 4), (6, 10)]);
+        let upper = orig.split_off(6);
+        assert_eq!(vec![UnaryRange { low: 0, high: 4 }], orig.ranges);
+        assert_eq!(vec![UnaryRange { low: 6, high: 10 }], upper.ranges);
+
+        let mut orig = DisjointRange::from_bounds_unchecked([(0u8, 4), (6, 10)]);
+        let upper = orig.split_off(4);
+        assert_eq!(vec![UnaryRange { low: 0, high: 3 }], orig.ranges);
+        assert_eq!(
+            vec![
+                UnaryRange { low: 4, high: 4 },
+                UnaryRange { low: 6, high: 10 }
+            ],
+            upper.ranges
+        );
+
+        let mut orig = DisjointRange::<u8>::entire();
+        let upper = orig.split_off(u8::MIN);
+        assert!(orig.ranges.is_empty());
+        assert_eq!(DisjointRange::<u8>::entire().ranges, upper.ranges);
+
+        let mut orig = DisjointRange::<u8>::entire();
+        let upper = orig.split_off(u8::MAX);
+        assert_eq!(
+            vec![UnaryRange {
+                low: 0,
+                high: u8::MAX - 1
+            }],
+            orig.ranges
+        );
+        assert_eq!(
+            vec![UnaryRange {
+                low: u8::MAX,
+                high: u8::MAX
+            }],
+            upper.ranges
+        );
+    }
+    #[test]
+    fn test_append() {
+        let mut orig = DisjointRange::from_bounds_unchecked([(0u8, 4), (12, 16)]);
+        let mut other = DisjointRange::from_bounds_unchecked([(5u8, 8), (20, 30)]);
+        orig.append(&mut other);
+        assert!(other.ranges.is_empty());
+        assert_eq!(
+            vec![
+                UnaryRange { low: 0, high: 8 },
+                UnaryRange { low: 12, high: 16 },
+                UnaryRange { low: 20, high: 30 }
+            ],
+            orig.ranges
+        );
+    }
+    #[test]
+    fn test_distance_to() {
+        let orig = DisjointRange::from_bounds_unchecked([(10u8, 20), (30, 40)]);
+        assert_eq!(Some(10), orig.distance_to(0));
+        assert_eq!(Some(1), orig.distance_to(9));
+        assert_eq!(Some(0), orig.distance_to(10));
+        assert_eq!(Some(0), orig.distance_to(15));
+        assert_eq!(Some(0), orig.distance_to(20));
+        assert_eq!(Some(2), orig.distance_to(22));
+        assert_eq!(Some(3), orig.distance_to(27));
+        assert_eq!(Some(0), orig.distance_to(40));
+        assert_eq!(Some(215), orig.distance_to(u8::MAX));
+        assert_eq!(None, DisjointRange::<u8>::empty().distance_to(5));
+    }
+    #[test]
+    fn test_nearest() {
+        let orig = DisjointRange::from_bounds_unchecked([(10u8, 20), (30, 40)]);
+        assert_eq!(Some(10), orig.nearest(0));
+        assert_eq!(Some(10), orig.nearest(10));
+        assert_eq!(Some(15), orig.nearest(15));
+        assert_eq!(Some(20), orig.nearest(24));
+        assert_eq!(Some(20), orig.nearest(25));
+        assert_eq!(Some(30), orig.nearest(26));
+        assert_eq!(Some(40), orig.nearest(u8::MAX));
+        assert_eq!(None, DisjointRange::<u8>::empty().nearest(5));
+    }
+    #[test]
+    fn test_complement_ref() {
+        let orig = DisjointRange::from_bounds_unchecked([(10u8, 50), (70, 100)]);
+        let complement = orig.complement_ref();
+        assert_eq!(orig.clone().complement().ranges, complement.ranges);
+        assert_eq!(2, orig.ranges.len());
+        let orig = UnaryRange::new_unchecked(10u8, 50u8);
+        assert_eq!(
+            orig.complement().unwrap().ranges,
+            orig.complement_ref().unwrap().ranges
+        );
+    }
+    #[test]
+    fn test_without_ref() {
+        let range = UnaryRange::new_unchecked(5, 10);
+        let ranges = [
+            UnaryRange::new_unchecked(3, 6),
+            UnaryRange::new_unchecked(7, 8),
+            UnaryRange::new_unchecked(0, 20),
+            UnaryRange::new_unchecked(12, 20),
+        ];
+        for other in ranges.iter() {
+            assert_eq!(range.without(*other), range.without_ref(other));
+        }
+    }
+    #[test]
+    fn test_intersect_range() {
+        let orig = DisjointRange::from_bounds_unchecked([(0u8, 4), (6, 10), (12, 16)]);
+        let actual = orig.intersect_range(3..=12);
+        assert_eq!(
+            vec![
+                UnaryRange { low: 3, high: 4 },
+                UnaryRange { low: 6, high: 10 },
+                UnaryRange { low: 12, high: 12 }
+            ],
+            actual.ranges
+        );
+        assert!(orig.intersect_range(17..=100).ranges.is_empty());
+        #[allow(clippy::reversed_empty_ranges)]
+        let empty = 10..=3;
+        assert!(orig.intersect_range(empty).ranges.is_empty());
+    }
+    #[test]
+    fn test_unary_range_bounds() {
+        use std::collections::BTreeMap;
+        use std::ops::{Bound, RangeBounds};
+        let range = UnaryRange::new_unchecked(3u8, 5);
+        assert_eq!(Bound::Included(&3), range.start_bound());
+        assert_eq!(Bound::Included(&5), range.end_bound());
+        let map: BTreeMap<u8, char> = (0..10).zip('a'..='j').collect();
+        let values: Vec<char> = map.range(range).map(|(_, v)| *v).collect();
+        assert_eq!(vec!['d', 'e', 'f'], values);
+    }
+    #[test]
+    fn test_toggle() {
+        let mut orig = DisjointRange::from_bounds_unchecked([(0u8, 4), (6, 10)]);
+        assert!(orig.toggle(5));
+        assert_eq!(vec![UnaryRange { low: 0, high: 10 }], orig.ranges);
+        assert!(!orig.toggle(5));
+        assert_eq!(
+            vec![
+                UnaryRange { low: 0, high: 4 },
+                UnaryRange { low: 6, high: 10 }
+            ],
+            orig.ranges
+        );
+        assert!(!orig.toggle(0));
+        assert!(!orig.toggle(10));
+        assert_eq!(
+            vec![
+                UnaryRange { low: 1, high: 4 },
+                UnaryRange { low: 6, high: 9 }
+            ],
+            orig.ranges
+        );
+        assert!(orig.toggle(20));
+        assert_eq!(Some(&UnaryRange { low: 20, high: 20 }), orig.ranges.last());
+        let mut orig = DisjointRange::from_bounds_unchecked([(3u8, 3)]);
+        assert!(!orig.toggle(3));
+        assert!(orig.ranges.is_empty());
+    }
+    #[test]
+    fn test_fill_gaps_smaller_than() {
+        let mut orig =
+            DisjointRange::from_bounds_unchecked([(0u8, 4), (6, 10), (13, 15), (20, 30)]);
+        orig.fill_gaps_smaller_than(0);
+        assert_eq!(4, orig.ranges.len());
+        orig.fill_gaps_smaller_than(2);
+        assert_eq!(
+            vec![
+                UnaryRange { low: 0, high: 15 },
+                UnaryRange { low: 20, high: 30 }
+            ],
+            orig.ranges
+        );
+        orig.fill_gaps_smaller_than(4);
+        assert_eq!(vec![UnaryRange { low: 0, high: 30 }], orig.ranges);
+
+        let mut orig = DisjointRange::from_bounds_unchecked([(-10i8, -5), (-3, 0)]);
+        orig.fill_gaps_smaller_than(-1);
+        assert_eq!(2, orig.ranges.len());
+        orig.fill_gaps_smaller_than(1);
+        assert_eq!(vec![UnaryRange { low: -10, high: 0 }], orig.ranges);
+    }
+    #[test]
+    fn test_gap_count() {
+        assert_eq!(0, DisjointRange::<u8>::empty().gap_count());
+        assert_eq!(0, DisjointRange::<u8>::entire().gap_count());
+        let orig = DisjointRange::from_bounds_unchecked([(0u8, 4), (6, 10), (13, 15)]);
+        assert_eq!(2, orig.gap_count());
+    }
+    #[test]
+    fn test_new_non_finite() {
+        assert!(UnaryRange::new(f64::NAN, 1.0).is_none());
+        assert!(UnaryRange::new(0.0, f64::NAN).is_none());
+        assert!(UnaryRange::new(f64::NAN, f64::NAN).is_none());
+        assert!(UnaryRange::<f32>::new_finite(0.0, f32::INFINITY).is_none());
+        assert!(UnaryRange::<f64>::new_finite(f64::NEG_INFINITY, 0.0).is_none());
+        assert!(UnaryRange::<f64>::new_finite(f64::NAN, 0.0).is_none());
+        assert!(UnaryRange::<f64>::new_finite(-1.5, 1.5).is_some());
+    }
+    #[test]
+    fn test_difference_with() {
+        let mut orig = DisjointRange::from_bounds_unchecked([(0u8, 10), (20, 30), (40, 50)]);
+        let other = DisjointRange::from_bounds_unchecked([(2u8, 3), (5, 22), (28, 45), (50, 60)]);
+        orig.difference_with(&other);
+        assert_eq!(
+            vec![
+                UnaryRange { low: 0, high: 1 },
+                UnaryRange { low: 4, high: 4 },
+                UnaryRange { low: 23, high: 27 },
+                UnaryRange { low: 46, high: 49 }
+            ],
+            orig.ranges
+        );
+    }
+    #[test]
+    fn test_difference_with_exhaustive() {
+        let orig =
+            DisjointRange::from_bounds_unchecked([(0u8, 10), (20, 30), (40, 50), (250, 255)]);
+        let other = DisjointRange::from_bounds_unchecked([
+            (0u8, 0),
+            (10, 20),
+            (25, 25),
+            (35, 39),
+            (255, 255),
+        ]);
+        let mut actual = orig.clone();
+        actual.difference_with(&other);
+        for v in u8::MIN..=u8::MAX {
+            assert_eq!(
+                orig.contains(&v) && !other.contains(&v),
+                actual.contains(&v),
+                "{v}"
+            );
+        }
+        actual.difference_with(&DisjointRange::entire());
+        assert!(actual.ranges.is_empty());
+    }
+    #[test]
+    fn test_tuple_round_trip() {
+        let range = UnaryRange::from_tuple((3u8, 7)).unwrap();
+        assert_eq!(UnaryRange { low: 3, high: 7 }, range);
+        assert_eq!((3, 7), range.as_tuple());
+        assert_eq!(Some(range), UnaryRange::from_tuple(range.as_tuple()));
+        assert!(UnaryRange::from_tuple((7u8, 3)).is_none());
+    }
+    #[test]
+    fn test_debug_compact() {
+        let orig = DisjointRange::from_bounds_unchecked([(1, 5), (8, 8), (12, 20)]);
+        assert_eq!("[1..=5, 8, 12..=20]", format!("{orig:?}"));
+        let orig = DisjointRange::from_bounds_unchecked([(-5, -3), (-1, -1)]);
+        assert_eq!("[-5..=-3, -1]", format!("{orig:?}"));
+        let orig = DisjointRange::from_bounds_unchecked([('a', 'f')]);
+        assert_eq!("['a'..='f']", format!("{orig:?}"));
+        assert_eq!("[]", format!("{:?}", DisjointRange::<u8>::empty()));
+    }
+    #[test]
+    fn test_debug_alternate() {
+        let orig = DisjointRange::from_bounds_unchecked([(1, 5)]);
+        let expected = "DisjointRange {\n    ranges: [\n        UnaryRange {\n            low: 1,\n            high: 5,\n        },\n    ],\n}";
+        assert_eq!(expected, format!("{orig:#?}"));
+    }
+    #[test]
+    fn test_index() {
+        let orig = DisjointRange::from_bounds_unchecked([(0u8, 4), (6, 10)]);
+        assert_eq!(2, orig.num_ranges());
+        assert_eq!(UnaryRange { low: 0, high: 4 }, orig[0]);
+        assert_eq!(UnaryRange { low: 6, high: 10 }, orig[1]);
+        assert_eq!(Some(&orig[1]), orig.get(1));
+        assert_eq!(None, orig.get(2));
+    }
+    #[test]
+    #[should_panic]
+    fn test_index_out_of_bounds() {
+        let orig = DisjointRange::from_bounds_unchecked([(0u8, 4), (6, 10)]);
+        let _ = orig[2];
+    }
+    fn assert_entire<T>()
+    where
+        T: Copy + Clone + Ord + Bounded + Stepped + std::fmt::Debug,
+    {
+        let entire = DisjointRange::<T>::entire();
+        assert!(entire.is_entire());
+        assert!(entire.contains(&T::MIN_VAL));
+        assert!(entire.contains(&T::MAX_VAL));
+        assert!(entire.complement().ranges.is_empty());
+        assert!(DisjointRange::<T>::empty().complement().is_entire());
+        assert!(!DisjointRange::<T>::empty().is_entire());
+    }
+    #[test]
+    fn test_entire() {
+        assert_entire::<u8>();
+        assert_entire::<u16>();
+        assert_entire::<u32>();
+        assert_entire::<u64>();
+        assert_entire::<u128>();
+        assert_entire::<usize>();
+        assert_entire::<i8>();
+        assert_entire::<i16>();
+        assert_entire::<i32>();
+        assert_entire::<i64>();
+        assert_entire::<i128>();
+        assert_entire::<isize>();
+        assert_entire::<char>();
+    }
+    #[test]
+    fn test_is_entire() {
+        let orig = DisjointRange::from_bounds_unchecked([(0u8, 4), (6, u8::MAX)]);
+        assert!(!orig.is_entire());
+        let orig = DisjointRange::from_bounds_unchecked([(0u8, 4), (5, u8::MAX)]);
+        assert!(orig.is_entire());
+        assert!(!DisjointRange::new_single_range_unchecked(0u8, 254).is_entire());
+        assert!(!DisjointRange::new_single_range_unchecked(-127i8, i8::MAX).is_entire());
+    }
+    #[test]
+    fn test_count() {
+        assert_eq!(1, UnaryRange::new_unchecked(5u8, 5).count());
+        assert_eq!(256, UnaryRange::new_unchecked(u8::MIN, u8::MAX).count());
+        assert_eq!(
+            u128::MAX,
+            UnaryRange::new_unchecked(u128::MIN, u128::MAX).count()
+        );
+        let orig = DisjointRange::from_bounds_unchecked([(1u8, 5), (10, 12)]);
+        assert_eq!(8, orig.count());
+        assert_eq!(0, DisjointRange::<u8>::empty().count());
+    }
+    #[test]
+    fn test_density() {
+        let orig = DisjointRange::from_bounds_unchecked([(1u8, 5), (10, 12)]);
+        assert!((orig.density() - 8.0 / 12.0).abs() < f64::EPSILON);
+        assert_eq!(
+            1.0,
+            DisjointRange::new_single_range_unchecked(3u8, 3).density()
+        );
+        assert_eq!(1.0, DisjointRange::<i64>::entire().density());
+        assert!(DisjointRange::<u8>::empty().density().is_nan());
+    }
+    #[test]
+    fn test_width_histogram() {
+        let orig = DisjointRange::from_bounds_unchecked([(1u8, 5), (10, 12), (20, 24), (30, 30)]);
+        let histogram = orig.width_histogram();
+        assert_eq!(
+            vec![(1, 1), (3, 1), (5, 2)],
+            histogram.into_iter().collect::<Vec<_>>()
+        );
+        assert!(DisjointRange::<u8>::empty().width_histogram().is_empty());
+    }
+    #[test]
+    fn test_range_windows() {
+        let orig = DisjointRange::from_bounds_unchecked([(1u8, 5), (10, 12), (20, 24)]);
+        let gaps: Vec<(u8, u8)> = orig
+            .range_windows(2)
+            .map(|pair| (pair[0].high, pair[1].low))
+            .collect();
+        assert_eq!(vec![(5, 10), (12, 20)], gaps);
+        assert_eq!(1, orig.range_windows(3).count());
+        assert_eq!(0, orig.range_windows(4).count());
+    }
+    #[test]
+    fn test_checked_count() {
+        assert_eq!(
+            Some(256),
+            UnaryRange::new_unchecked(u8::MIN, u8::MAX).checked_count()
+        );
+        assert_eq!(
+            None,
+            UnaryRange::new_unchecked(u128::MIN, u128::MAX).checked_count()
+        );
+        assert_eq!(
+            Some(u128::MAX),
+            UnaryRange::new_unchecked(1, u128::MAX).checked_count()
+        );
+        assert_eq!(None, DisjointRange::<u128>::entire().checked_count());
+        assert_eq!(None, DisjointRange::<i128>::entire().checked_count());
+        assert_eq!(Some(0), DisjointRange::<u128>::empty().checked_count());
+        let orig = DisjointRange::from_bounds_unchecked([
+            (0, u128::MAX / 2),
+            (u128::MAX / 2 + 2, u128::MAX),
+        ]);
+        assert_eq!(Some(u128::MAX), orig.checked_count());
+        let orig = DisjointRange::from_bounds_unchecked([
+            (0, u128::MAX / 2),
+            (u128::MAX / 2 + 1, u128::MAX),
+        ]);
+        assert_eq!(None, orig.checked_count());
+        assert_eq!(u128::MAX, orig.count());
+    }
+    #[test]
+    fn test_union_with() {
+        let mut orig = DisjointRange::from_bounds_unchecked([(0u8, 10), (20, 30), (40, 50)]);
+        let other = DisjointRange::from_bounds_unchecked([(2u8, 3), (11, 15), (25, 35), (60, 70)]);
+        orig.union_with(&other);
+        assert_eq!(
+            vec![
+                UnaryRange { low: 0, high: 15 },
+                UnaryRange { low: 20, high: 35 },
+                UnaryRange { low: 40, high: 50 },
+                UnaryRange { low: 60, high: 70 }
+            ],
+            orig.ranges
+        );
+        orig.union_with(&DisjointRange::empty());
+        assert_eq!(4, orig.ranges.len());
+        orig.union_with(&DisjointRange::entire());
+        assert!(orig.is_entire());
+    }
+    #[test]
+    fn test_intersect_with() {
+        let mut orig = DisjointRange::from_bounds_unchecked([(0u8, 10), (20, 30), (40, 50)]);
+        let other = DisjointRange::from_bounds_unchecked([(2u8, 3), (5, 22), (28, 45), (50, 60)]);
+        orig.intersect_with(&other);
+        assert_eq!(
+            vec![
+                UnaryRange { low: 2, high: 3 },
+                UnaryRange { low: 5, high: 10 },
+                UnaryRange { low: 20, high: 22 },
+                UnaryRange { low: 28, high: 30 },
+                UnaryRange { low: 40, high: 45 },
+                UnaryRange { low: 50, high: 50 }
+            ],
+            orig.ranges
+        );
+        orig.intersect_with(&DisjointRange::empty());
+        assert!(orig.ranges.is_empty());
+    }
+    #[test]
+    #[allow(deprecated)]
+    fn test_add_disjoint_range() {
+        let mut orig = DisjointRange::from_bounds_unchecked([(0u8, 10)]);
+        orig.add_disjoint_range(DisjointRange::from_bounds_unchecked([(11u8, 20)]));
+        assert_eq!(vec![UnaryRange { low: 0, high: 20 }], orig.ranges);
+    }
+    #[test]
+    fn test_extend_values() {
+        let mut orig = DisjointRange::from_bounds_unchecked([(10u32, 12)]);
+        orig.extend([1u32, 2, 5, 9, 3, 13, 20]);
+        assert_eq!(
+            vec![
+                UnaryRange { low: 1, high: 3 },
+                UnaryRange { low: 5, high: 5 },
+                UnaryRange { low: 9, high: 13 },
+                UnaryRange { low: 20, high: 20 }
+            ],
+            orig.ranges
+        );
+    }
+    #[test]
+    fn test_extend_ranges() {
+        let mut orig = DisjointRange::empty();
+        orig.extend([
+            UnaryRange::new_unchecked(10u8, 20),
+            UnaryRange::new_unchecked(0, 5),
+            UnaryRange::new_unchecked(4, 9),
+        ]);
+        assert_eq!(vec![UnaryRange { low: 0, high: 20 }], orig.ranges);
+    }
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_par_intersect_all() {
+        let sets: Vec<DisjointRange<u32>> = (0u32..200)
+            .map(|i| DisjointRange::from_bounds_unchecked([(i, 10_000 - i), (20_000 + i, 30_000)]))
+            .collect();
+        let mut expected = DisjointRange::entire();
+        for set in sets.iter() {
+            expected.intersect_with(set);
+        }
+        let actual = DisjointRange::par_intersect_all(sets);
+        assert_eq!(expected.ranges, actual.ranges);
+        assert_eq!(
+            vec![
+                UnaryRange {
+                    low: 199,
+                    high: 9801
+                },
+                UnaryRange {
+                    low: 20199,
+                    high: 30000
+                }
+            ],
+            actual.ranges
+        );
+        assert!(DisjointRange::<u32>::par_intersect_all(Vec::new()).is_entire());
+    }
+    #[test]
+    fn test_range_containing() {
+        let orig = DisjointRange::from_bounds_unchecked([(0u8, 4), (6, 10), (12, 16)]);
+        assert_eq!(
+            Some(&UnaryRange { low: 0, high: 4 }),
+            orig.range_containing(&0)
+        );
+        assert_eq!(
+            Some(&UnaryRange { low: 6, high: 10 }),
+            orig.range_containing(&8)
+        );
+        assert_eq!(
+            Some(&UnaryRange { low: 12, high: 16 }),
+            orig.range_containing(&16)
+        );
+        assert_eq!(None, orig.range_containing(&5));
+        assert_eq!(None, orig.range_containing(&17));
+        assert_eq!(None, DisjointRange::<u8>::empty().range_containing(&0));
+    }
+    #[test]
+    fn test_complement_single_value() {
+        let complement = UnaryRange::new_unchecked(0u8, 0).complement().unwrap();
+        assert_eq!(
+            vec![UnaryRange {
+                low: 1,
+                high: u8::MAX
+            }],
+            complement.ranges
+        );
+        let complement = UnaryRange::new_unchecked(u8::MAX, u8::MAX)
+            .complement()
+            .unwrap();
+        assert_eq!(
+            vec![UnaryRange {
+                low: 0,
+                high: u8::MAX - 1
+            }],
+            complement.ranges
+        );
+        let complement = UnaryRange::new_unchecked(100u8, 100).complement().unwrap();
+        assert_eq!(
+            vec![
+                UnaryRange { low: 0, high: 99 },
+                UnaryRange {
+                    low: 101,
+                    high: u8::MAX
+                }
+            ],
+            complement.ranges
+        );
+        let complement = UnaryRange::new_unchecked(i8::MIN, i8::MIN)
+            .complement()
+            .unwrap();
+        assert_eq!(
+            vec![UnaryRange {
+                low: i8::MIN + 1,
+                high: i8::MAX
+            }],
+            complement.ranges
+        );
+    }
+    #[test]
+    fn test_complement_disjoint_single_values() {
+        let complement = DisjointRange::from_bounds_unchecked([(0u8, 0)]).complement();
+        assert_eq!(
+            vec![UnaryRange {
+                low: 1,
+                high: u8::MAX
+            }],
+            complement.ranges
+        );
+        let complement = DisjointRange::from_bounds_unchecked([(u8::MAX, u8::MAX)]).complement();
+        assert_eq!(
+            vec![UnaryRange {
+                low: 0,
+                high: u8::MAX - 1
+            }],
+            complement.ranges
+        );
+        let complement =
+            DisjointRange::from_bounds_unchecked([(0u8, 0), (100, 100), (u8::MAX, u8::MAX)])
+                .complement();
+        assert_eq!(
+            vec![
+                UnaryRange { low: 1, high: 99 },
+                UnaryRange {
+                    low: 101,
+                    high: u8::MAX - 1
+                }
+            ],
+            complement.ranges
+        );
+    }
+    #[test]
+    fn test_char_count_skips_surrogates() {
+        let range = UnaryRange::new_unchecked('\u{D000}', '\u{F000}');
+        assert_eq!(range.count(), range.into_iter().count() as u128);
+        assert_eq!(0x2001 - 0x800, range.count());
+        assert_eq!(
+            Some(0x110000 - 0x800),
+            DisjointRange::<char>::entire().checked_count()
+        );
+    }
+    #[test]
+    fn test_from_bounds_normalizes() {
+        let expected = vec![
+            UnaryRange { low: 0, high: 10 },
+            UnaryRange { low: 20, high: 30 },
+        ];
+        let bounds = [(20u8, 25), (5, 10), (24, 30), (0, 4)];
+        let orig = DisjointRange::from_bounds(bounds).unwrap();
+        assert_eq!(expected, orig.ranges);
+        assert!(orig.contains(&3));
+        assert!(orig.contains(&27));
+        let orig = DisjointRange::from_bounds_unchecked(bounds);
+        assert_eq!(expected, orig.ranges);
+        assert!(DisjointRange::from_bounds([(0u8, 4), (10, 5)]).is_none());
+    }
+    #[test]
+    fn test_retain_values() {
+        let mut orig = DisjointRange::from_bounds_unchecked([(0u8, 5), (10, 11), (20, 22)]);
+        orig.retain_values(|v| v % 2 == 0);
+        let expected = vec![
+            UnaryRange { low: 0, high: 0 },
+            UnaryRange { low: 2, high: 2 },
+            UnaryRange { low: 4, high: 4 },
+            UnaryRange { low: 10, high: 10 },
+            UnaryRange { low: 20, high: 20 },
+            UnaryRange { low: 22, high: 22 },
+        ];
+        assert_eq!(expected, orig.ranges);
+        let mut orig = DisjointRange::from_bounds_unchecked([(0u8, 9), (250, u8::MAX)]);
+        orig.retain_values(|v| !(3..=6).contains(v) && *v != 252);
+        let expected = vec![
+            UnaryRange { low: 0, high: 2 },
+            UnaryRange { low: 7, high: 9 },
+            UnaryRange {
+                low: 250,
+                high: 251,
+            },
+            UnaryRange {
+                low: 253,
+                high: u8::MAX,
+            },
+        ];
+        assert_eq!(expected, orig.ranges);
+        orig.retain_values(|_| false);
+        assert!(orig.ranges.is_empty());
+    }
+    #[test]
+    fn test_ranges_in_window() {
+        let orig = DisjointRange::from_bounds_unchecked([(0u8, 5), (10, 15), (20, 25), (30, 35)]);
+        assert_eq!(
+            4,
+            orig.ranges_in_window(&UnaryRange::new_unchecked(0, u8::MAX))
+        );
+        assert_eq!(2, orig.ranges_in_window(&UnaryRange::new_unchecked(5, 10)));
+        assert_eq!(3, orig.ranges_in_window(&UnaryRange::new_unchecked(12, 30)));
+        assert_eq!(1, orig.ranges_in_window(&UnaryRange::new_unchecked(21, 22)));
+        assert_eq!(0, orig.ranges_in_window(&UnaryRange::new_unchecked(6, 9)));
+        assert_eq!(
+            0,
+            orig.ranges_in_window(&UnaryRange::new_unchecked(36, u8::MAX))
+        );
+        assert_eq!(
+            0,
+            DisjointRange::<u8>::empty().ranges_in_window(&UnaryRange::new_unchecked(0, 9))
+        );
+    }
+    #[test]
+    fn test_intersection_count() {
+        let a = DisjointRange::from_bounds_unchecked([(0u8, 10), (20, 30), (40, 50)]);
+        let b = DisjointRange::from_bounds_unchecked([(5u8, 25), (29, 45)]);
+        let mut expected = a.clone();
+        expected.intersect_with(&b);
+        assert_eq!(expected.count(), a.intersection_count(&b));
+        assert_eq!(6 + 6 + 2 + 6, a.intersection_count(&b));
+        assert_eq!(a.intersection_count(&b), b.intersection_count(&a));
+        assert_eq!(0, a.intersection_count(&DisjointRange::empty()));
+        assert_eq!(a.count(), a.intersection_count(&DisjointRange::entire()));
+        let entire = DisjointRange::<u128>::entire();
+        assert_eq!(u128::MAX, entire.intersection_count(&entire));
+    }
+    #[test]
+    fn test_meld() {
+        let mut orig = DisjointRange {
+            ranges: vec![
+                UnaryRange::new_unchecked(20u8, 25),
+                UnaryRange::new_unchecked(0, 5),
+                UnaryRange::new_unchecked(24, 30),
+                UnaryRange::new_unchecked(6, 8),
+            ],
+            mode: StepMode::Saturating,
+        };
+        orig.meld();
+        let expected = vec![
+            UnaryRange { low: 0, high: 8 },
+            UnaryRange { low: 20, high: 30 },
+        ];
+        assert_eq!(expected, orig.ranges);
+        assert!(orig.contains(&27));
+        orig.meld();
+        assert_eq!(expected, orig.ranges);
+    }
+    #[test]
+    fn test_from_inclusive_ranges() {
+        let orig =
+            DisjointRange::from_inclusive_ranges([20u8..=25, 0..=4, 5..=10, 24..=30]).unwrap();
+        let expected = vec![
+            UnaryRange { low: 0, high: 10 },
+            UnaryRange { low: 20, high: 30 },
+        ];
+        assert_eq!(expected, orig.ranges);
+        assert!(
+            DisjointRange::from_inclusive_ranges([0u8..=4, std::ops::RangeInclusive::new(10, 5)])
+                .is_none()
+        );
+        let mut exhausted = 3u8..=3;
+        exhausted.next();
+        assert!(DisjointRange::from_inclusive_ranges([0u8..=1, exhausted]).is_none());
+        let orig = DisjointRange::<u8>::from_inclusive_ranges([]).unwrap();
+        assert!(orig.ranges.is_empty());
+    }
+    #[test]
+    fn test_from_cidr_v4() {
+        use crate::error::RangeError;
+        use std::net::Ipv4Addr;
+
+        let orig = DisjointRange::from_cidr_v4("10.0.0.0/8").unwrap();
+        let expected = vec![UnaryRange {
+            low: Ipv4Addr::new(10, 0, 0, 0),
+            high: Ipv4Addr::new(10, 255, 255, 255),
+        }];
+        assert_eq!(expected, orig.ranges);
+        assert_eq!(
+            UnaryRange::new_unchecked(Ipv4Addr::new(192, 168, 1, 7), Ipv4Addr::new(192, 168, 1, 7)),
+            UnaryRange::from_cidr_v4("192.168.1.7/32").unwrap()
+        );
+        assert!(
+            DisjointRange::from_cidr_v4("0.0.0.0/0")
+                .unwrap()
+                .is_entire()
+        );
+        for bad in [
+            "10.0.0.0",
+            "10.0.0.0/33",
+            "10.0.0.1/8",
+            "10.0.0/8",
+            "10.0.0.0/x",
+        ] {
+            assert_eq!(Err(RangeError::InvalidCidr), UnaryRange::from_cidr_v4(bad));
+        }
+    }
+    #[test]
+    fn test_to_cidrs() {
+        use std::net::Ipv4Addr;
+
+        let range =
+            UnaryRange::new_unchecked(Ipv4Addr::new(10, 0, 0, 1), Ipv4Addr::new(10, 0, 0, 10));
+        let expected = vec![
+            (Ipv4Addr::new(10, 0, 0, 1), 32),
+            (Ipv4Addr::new(10, 0, 0, 2), 31),
+            (Ipv4Addr::new(10, 0, 0, 4), 30),
+            (Ipv4Addr::new(10, 0, 0, 8), 31),
+            (Ipv4Addr::new(10, 0, 0, 10), 32),
+        ];
+        assert_eq!(expected, range.to_cidrs());
+        assert_eq!(
+            vec![(Ipv4Addr::UNSPECIFIED, 0)],
+            DisjointRange::<Ipv4Addr>::entire().to_cidrs()
+        );
+        let mut orig = DisjointRange::from_cidr_v4("10.0.0.0/8").unwrap();
+        orig.add_unary_range(UnaryRange::from_cidr_v4("255.255.255.254/31").unwrap());
+        assert_eq!(
+            vec![
+                (Ipv4Addr::new(10, 0, 0, 0), 8),
+                (Ipv4Addr::new(255, 255, 255, 254), 31)
+            ],
+            orig.to_cidrs()
+        );
+        for (addr, prefix) in expected {
+            let block = UnaryRange::from_cidr_v4(&format!("{addr}/{prefix}")).unwrap();
+            assert_eq!(vec![(addr, prefix)], block.to_cidrs());
+        }
+    }
+    #[test]
+    fn test_overlay() {
+        use super::Membership::{Both, OnlyLeft, OnlyRight};
+
+        let left = DisjointRange::from_bounds_unchecked([(0u8, 10), (20, 30), (50, 60)]);
+        let right = DisjointRange::from_bounds_unchecked([(5u8, 25), (28, 30), (40, 45), (70, 80)]);
+        let expected = vec![
+            (UnaryRange { low: 0, high: 4 }, OnlyLeft),
+            (UnaryRange { low: 5, high: 10 }, Both),
+            (UnaryRange { low: 11, high: 19 }, OnlyRight),
+            (UnaryRange { low: 20, high: 25 }, Both),
+            (UnaryRange { low: 26, high: 27 }, OnlyLeft),
+            (UnaryRange { low: 28, high: 30 }, Both),
+            (UnaryRange { low: 40, high: 45 }, OnlyRight),
+            (UnaryRange { low: 50, high: 60 }, OnlyLeft),
+            (UnaryRange { low: 70, high: 80 }, OnlyRight),
+        ];
+        assert_eq!(expected, left.overlay(&right));
+        let flipped: Vec<_> = right
+            .overlay(&left)
+            .into_iter()
+            .map(|(range, membership)| {
+                let membership = match membership {
+                    OnlyLeft => OnlyRight,
+                    OnlyRight => OnlyLeft,
+                    Both => Both,
+                };
+                (range, membership)
+            })
+            .collect();
+        assert_eq!(expected, flipped);
+        let expected: Vec<_> = left.ranges.iter().map(|r| (*r, OnlyLeft)).collect();
+        assert_eq!(expected, left.overlay(&DisjointRange::empty()));
+        let entire = DisjointRange::<u8>::entire();
+        assert_eq!(
+            vec![(
+                UnaryRange {
+                    low: 0,
+                    high: u8::MAX
+                },
+                Both
+            )],
+            entire.overlay(&entire)
+        );
+    }
+    #[test]
+    fn test_ranges_with_gaps() {
+        let orig = DisjointRange::from_bounds_unchecked([(0u8, 5), (8, 8), (10, u8::MAX)]);
+        let expected = vec![
+            (
+                UnaryRange { low: 0, high: 5 },
+                Some(UnaryRange { low: 6, high: 7 }),
+            ),
+            (
+                UnaryRange { low: 8, high: 8 },
+                Some(UnaryRange { low: 9, high: 9 }),
+            ),
+            (
+                UnaryRange {
+                    low: 10,
+                    high: u8::MAX,
+                },
+                None,
+            ),
+        ];
+        assert_eq!(expected, orig.ranges_with_gaps().collect::<Vec<_>>());
+        assert_eq!(0, DisjointRange::<u8>::empty().ranges_with_gaps().count());
+    }
+    #[test]
+    fn test_const_constructors() {
+        const PORTS: UnaryRange<u16> = UnaryRange::new_unchecked(1024, 49151);
+        static NOTHING: DisjointRange<u16> = DisjointRange::empty();
+        assert_eq!(
+            UnaryRange {
+                low: 1024,
+                high: 49151
+            },
+            PORTS
+        );
+        assert!(NOTHING.ranges.is_empty());
+        assert!(!NOTHING.contains(&1024));
+    }
+    #[test]
+    fn test_meld_at_bounds() {
+        let mut ranges = vec![
+            UnaryRange::new_unchecked(0u8, 0),
+            UnaryRange::new_unchecked(1, 1),
+            UnaryRange::new_unchecked(250, u8::MAX),
+            UnaryRange::new_unchecked(u8::MAX, u8::MAX),
+        ];
+        DisjointRange::meld_ranges(&mut ranges);
+        let expected = vec![
+            UnaryRange { low: 0, high: 1 },
+            UnaryRange {
+                low: 250,
+                high: u8::MAX,
+            },
+        ];
+        assert_eq!(expected, ranges);
+        let mut ranges = vec![
+            UnaryRange::new_unchecked(i8::MIN, 0),
+            UnaryRange::new_unchecked(1, i8::MAX),
+        ];
+        DisjointRange::meld_ranges(&mut ranges);
+        assert_eq!(
+            vec![UnaryRange {
+                low: i8::MIN,
+                high: i8::MAX
+            }],
+            ranges
+        );
+    }
+
+    /// Like a `u8`, but wraps around at the bounds instead of saturating
+    #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+    struct Wraps(u8);
+
+    impl Bounded for Wraps {
+        const MIN_VAL: Wraps = Wraps(u8::MIN);
+        const MAX_VAL: Wraps = Wraps(u8::MAX);
+    }
+
+    impl Stepped for Wraps {
+        const STEP: Wraps = Wraps(1);
+        fn increment(&self) -> Self {
+            Wraps(self.0.wrapping_add(1))
+        }
+        fn decrement(&self) -> Self {
+            Wraps(self.0.wrapping_sub(1))
+        }
+    }
+
+    #[test]
+    fn test_step_mode_meld() {
+        let range = |low, high| UnaryRange::new_unchecked(Wraps(low), Wraps(high));
+        let ranges = vec![range(u8::MAX, u8::MAX), range(250, u8::MAX)];
+        let saturating = DisjointRange::from_ranges(ranges.clone());
+        assert_eq!(StepMode::Saturating, saturating.step_mode());
+        assert_eq!(
+            vec![range(250, u8::MAX), range(u8::MAX, u8::MAX)],
+            saturating.ranges
+        );
+        let checked = DisjointRange::from_ranges_with_mode(ranges, StepMode::Checked);
+        assert_eq!(vec![range(250, u8::MAX)], checked.ranges);
+        assert_eq!(
+            vec![range(250, u8::MAX)],
+            saturating.with_step_mode(StepMode::Checked).ranges
+        );
+
+        let ranges = vec![
+            UnaryRange::new_unchecked(u8::MAX, u8::MAX),
+            UnaryRange::new_unchecked(250, u8::MAX),
+        ];
+        assert_eq!(
+            DisjointRange::from_ranges(ranges.clone()).ranges,
+            DisjointRange::from_ranges_with_mode(ranges, StepMode::Checked).ranges
+        );
+    }
+
+    #[test]
+    fn test_step_mode_union() {
+        let range = |low, high| UnaryRange::new_unchecked(Wraps(low), Wraps(high));
+        let mut checked =
+            DisjointRange::from_ranges_with_mode(vec![range(200, u8::MAX)], StepMode::Checked);
+        checked.union_with(&DisjointRange::from_ranges(vec![range(250, u8::MAX)]));
+        assert_eq!(vec![range(200, u8::MAX)], checked.ranges);
+        assert!(checked.is_canonical());
+        let mut saturating = DisjointRange::from_ranges(vec![range(200, u8::MAX)]);
+        saturating.union_with(&DisjointRange::from_ranges(vec![range(250, u8::MAX)]));
+        assert_eq!(
+            vec![range(200, u8::MAX), range(250, u8::MAX)],
+            saturating.ranges
+        );
+
+        let ranges = vec![range(250, u8::MAX), range(200, u8::MAX)];
+        let (normalized, merged) =
+            DisjointRange::normalize_reporting_with_mode(ranges.clone(), StepMode::Checked);
+        assert_eq!(vec![range(200, u8::MAX)], normalized.ranges);
+        assert_eq!(vec![(0, 1)], merged);
+        let (_, merged) = DisjointRange::normalize_reporting(ranges);
+        assert!(merged.is_empty());
+
+        let mut checked = DisjointRange::from_ranges_with_mode(
+            vec![range(200, 240), range(250, u8::MAX)],
+            StepMode::Checked,
+        );
+        checked.fill_gaps_smaller_than(Wraps(9));
+        assert_eq!(vec![range(200, u8::MAX)], checked.ranges);
+    }
+
+    #[test]
+    fn test_step_mode_complement() {
+        let range = |low, high| UnaryRange::new_unchecked(Wraps(low), Wraps(high));
+        let orig = DisjointRange::from_ranges(vec![range(u8::MIN, 9), range(250, u8::MAX)]);
+        let checked = orig.clone().with_step_mode(StepMode::Checked);
+        assert_eq!(vec![range(10, 249)], checked.complement_ref().ranges);
+        assert_eq!(
+            vec![range(10, 249)],
+            checked.iter_complement().collect::<Vec<_>>()
+        );
+        assert_eq!(StepMode::Checked, checked.clone().complement().step_mode());
+        assert_eq!(vec![range(10, 249)], checked.complement().ranges);
+        // stepping below `MIN_VAL` and above `MAX_VAL` wraps, so each looks like a gap
+        let saturating = vec![
+            range(u8::MIN, u8::MAX),
+            range(10, 249),
+            range(u8::MIN, u8::MAX),
+        ];
+        assert_eq!(saturating, orig.complement_ref().ranges);
+        assert_eq!(saturating, orig.complement().ranges);
+
+        let orig = DisjointRange::from_ranges(vec![
+            UnaryRange::new_unchecked(u8::MIN, 9),
+            UnaryRange::new_unchecked(250, u8::MAX),
+        ]);
+        let expected = vec![UnaryRange { low: 10, high: 249 }];
+        assert_eq!(expected, orig.complement_ref().ranges);
+        assert_eq!(
+            expected,
+            orig.with_step_mode(StepMode::Checked).complement().ranges
+        );
+    }
+    #[test]
+    fn test_unary_contains_range() {
+        let outer = UnaryRange::new_unchecked(10u8, 20);
+        assert!(outer.contains_range(&outer));
+        assert!(outer.contains_range(&UnaryRange::new_unchecked(10, 10)));
+        assert!(outer.contains_range(&UnaryRange::new_unchecked(12, 20)));
+        assert!(!outer.contains_range(&UnaryRange::new_unchecked(9, 15)));
+        assert!(!outer.contains_range(&UnaryRange::new_unchecked(15, 21)));
+        assert!(!outer.contains_range(&UnaryRange::new_unchecked(0, u8::MAX)));
+        assert!(!outer.contains_range(&UnaryRange::new_unchecked(30, 40)));
+    }
+    #[test]
+    fn test_align_to() {
+        let orig =
+            DisjointRange::from_bounds_unchecked([(10u32, 5000), (20000, 20000), (24600, 24700)]);
+        let expected = vec![
+            UnaryRange { low: 0, high: 8191 },
+            UnaryRange {
+                low: 16384,
+                high: 20479,
+            },
+            UnaryRange {
+                low: 24576,
+                high: 28671,
+            },
+        ];
+        assert_eq!(expected, orig.align_to(4096).ranges);
+        // ranges landing in the same or adjacent blocks are melded
+        let orig = DisjointRange::from_bounds_unchecked([(10u32, 20), (30, 40), (5000, 5000)]);
+        assert_eq!(
+            vec![UnaryRange { low: 0, high: 8191 }],
+            orig.align_to(4096).ranges
+        );
+        let orig = DisjointRange::new_single_range_unchecked(0u32, 4095);
+        assert_eq!(orig.ranges, orig.align_to(4096).ranges);
+        let orig = DisjointRange::new_single_range_unchecked(-5i8, 5);
+        assert_eq!(
+            vec![UnaryRange { low: -8, high: 7 }],
+            orig.align_to(4).ranges
+        );
+    }
+    #[test]
+    fn test_align_to_bounds() {
+        let aligned = DisjointRange::new_single_range_unchecked(210u8, 220).align_to(100);
+        assert_eq!(
+            vec![UnaryRange {
+                low: 200,
+                high: u8::MAX
+            }],
+            aligned.ranges
+        );
+        let aligned = DisjointRange::new_single_range_unchecked(-127i8, -126).align_to(3);
+        assert_eq!(
+            vec![UnaryRange {
+                low: i8::MIN,
+                high: -124
+            }],
+            aligned.ranges
+        );
+        let aligned = DisjointRange::new_single_range_unchecked(i8::MIN, i8::MIN).align_to(i8::MAX);
+        assert_eq!(
+            vec![UnaryRange {
+                low: i8::MIN,
+                high: i8::MIN
+            }],
+            aligned.ranges
+        );
+        assert!(DisjointRange::<u8>::entire().align_to(7).is_entire());
+        assert!(DisjointRange::<u8>::empty().align_to(7).ranges.is_empty());
+    }
+    #[test]
+    #[should_panic(expected = "stride must be positive")]
+    fn test_align_to_zero_stride() {
+        DisjointRange::new_single_range_unchecked(1u8, 2).align_to(0);
+    }
+    #[test]
+    fn test_iter_values_bounded() {
+        let orig = DisjointRange::from_bounds_unchecked([(0u8, 2), (10, 11), (250, u8::MAX)]);
+        assert_eq!(
+            vec![0, 1, 2, 10],
+            orig.iter_values_bounded(4).collect::<Vec<_>>()
+        );
+        assert_eq!(11, orig.iter_values_bounded(100).count());
+        assert_eq!(0, orig.iter_values_bounded(0).count());
+    }
+    #[test]
+    fn test_try_to_values() {
+        let orig = DisjointRange::from_bounds_unchecked([(0u8, 2), (10, 11), (254, u8::MAX)]);
+        assert_eq!(Some(vec![0, 1, 2, 10, 11, 254, 255]), orig.try_to_values(7));
+        assert_eq!(None, orig.try_to_values(6));
+        assert_eq!(Some(vec![]), DisjointRange::<u8>::empty().try_to_values(0));
+        assert_eq!(
+            None,
+            DisjointRange::<u128>::entire().try_to_values(usize::MAX)
+        );
+    }
+    #[test]
+    fn test_complement_within() {
+        let universe = UnaryRange::new_unchecked(0u8, 100);
+        let expected = vec![
+            UnaryRange { low: 0, high: 9 },
+            UnaryRange { low: 21, high: 100 },
+        ];
+        assert_eq!(
+            expected,
+            UnaryRange::new_unchecked(10, 20)
+                .complement_within(&universe)
+                .ranges
+        );
+        let expected = vec![UnaryRange { low: 51, high: 100 }];
+        assert_eq!(
+            expected,
+            UnaryRange::new_unchecked(0, 50)
+                .complement_within(&universe)
+                .ranges
+        );
+        assert_eq!(
+            vec![universe],
+            UnaryRange::new_unchecked(150, 200)
+                .complement_within(&universe)
+                .ranges
+        );
+        assert!(
+            UnaryRange::new_unchecked(0, u8::MAX)
+                .complement_within(&universe)
+                .ranges
+                .is_empty()
+        );
+    }
+    #[test]
+    fn test_eq_unary_range() {
+        let range = UnaryRange::new_unchecked(5u8, 10);
+        let orig = DisjointRange::new_single_range_unchecked(5u8, 10);
+        assert_eq!(orig, range);
+        assert_eq!(range, orig);
+        assert_ne!(orig, UnaryRange::new_unchecked(5, 11));
+        let orig = DisjointRange::from_bounds_unchecked([(5u8, 10), (20, 30)]);
+        assert_ne!(orig, range);
+        assert_ne!(range, orig);
+        assert_ne!(DisjointRange::empty(), range);
+    }
+    #[test]
+    fn test_checked_shift() {
+        let orig = DisjointRange::from_bounds_unchecked([(10u8, 20), (30, 40)]);
+        let expected = vec![
+            UnaryRange { low: 25, high: 35 },
+            UnaryRange { low: 45, high: 55 },
+        ];
+        assert_eq!(expected, orig.checked_shift(15).unwrap().ranges);
+        assert_eq!(orig.ranges, orig.checked_shift(0).unwrap().ranges);
+        let shifted = orig.checked_shift(u8::MAX - 40).unwrap();
+        assert_eq!(u8::MAX, shifted.ranges[1].high);
+        assert!(orig.checked_shift(u8::MAX - 39).is_none());
+        let orig = DisjointRange::from_bounds_unchecked([(-100i8, -50), (0, 10)]);
+        let expected = vec![
+            UnaryRange {
+                low: -128,
+                high: -78,
+            },
+            UnaryRange {
+                low: -28,
+                high: -18,
+            },
+        ];
+        assert_eq!(expected, orig.checked_shift(-28).unwrap().ranges);
+        assert!(orig.checked_shift(-29).is_none());
+        assert!(orig.checked_shift(i8::MAX - 10).is_some());
+        assert!(orig.checked_shift(i8::MAX - 9).is_none());
+        assert!(
+            DisjointRange::<u8>::empty()
+                .checked_shift(u8::MAX)
+                .is_some()
+        );
+    }
+    #[test]
+    fn test_iter_complement() {
+        let orig = DisjointRange::from_bounds_unchecked([(5u8, 10), (20, 30)]);
+        let expected = vec![
+            UnaryRange { low: 0, high: 4 },
+            UnaryRange { low: 11, high: 19 },
+            UnaryRange {
+                low: 31,
+                high: u8::MAX,
+            },
+        ];
+        assert_eq!(expected, orig.iter_complement().collect::<Vec<_>>());
+        let orig = DisjointRange::from_bounds_unchecked([(0u8, 10), (20, u8::MAX)]);
+        assert_eq!(
+            vec![UnaryRange { low: 11, high: 19 }],
+            orig.iter_complement().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![UnaryRange {
+                low: 0,
+                high: u8::MAX
+            }],
+            DisjointRange::<u8>::empty()
+                .iter_complement()
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(0, DisjointRange::<u8>::entire().iter_complement().count());
+    }
+    #[test]
+    fn test_contains_bounds() {
+        use std::ops::Bound::{Excluded, Unbounded};
+
+        let orig = DisjointRange::from_bounds_unchecked([(0u8, 10), (20, 30), (40, u8::MAX)]);
+        assert!(orig.contains_bounds(2..=10));
+        assert!(orig.contains_bounds(2..11));
+        assert!(!orig.contains_bounds(2..=11));
+        assert!(orig.contains_bounds(..5));
+        assert!(orig.contains_bounds(..=10));
+        assert!(orig.contains_bounds(40..));
+        assert!(!orig.contains_bounds(30..));
+        assert!(!orig.contains_bounds(..));
+        assert!(DisjointRange::<u8>::entire().contains_bounds(..));
+        assert!(orig.contains_bounds((Excluded(19), Excluded(31))));
+        assert!(!orig.contains_bounds((Excluded(18), Excluded(31))));
+        // no values at all
+        assert!(orig.contains_bounds(15..15));
+        assert!(orig.contains_bounds((Excluded(u8::MAX), Unbounded)));
+        assert!(DisjointRange::<u8>::empty().contains_bounds(..0));
+        assert!(!DisjointRange::<u8>::empty().contains_bounds(0..=0));
+    }
+    #[test]
+    fn test_display() {
+        let orig = DisjointRange::from_bounds_unchecked([(1u8, 5), (8, 8), (12, 20)]);
+        assert_eq!("1-5,8,12-20", orig.to_string());
+        let orig = DisjointRange::from_bounds_unchecked([(-10i8, -5), (0, 0)]);
+        assert_eq!("-10--5,0", orig.to_string());
+        assert_eq!("", DisjointRange::<u8>::empty().to_string());
+    }
+    #[test]
+    fn test_from_str() {
+        use crate::error::RangeError;
 
-    fn next(&mut self) -> Option<Self::Item> {
-        self.ranges.pop_front()
+        let orig: DisjointRange<u8> = "12-20, 1-5,8 ,4".parse().unwrap();
+        let expected = vec![
+            UnaryRange { low: 1, high: 5 },
+            UnaryRange { low: 8, high: 8 },
+            UnaryRange { low: 12, high: 20 },
+        ];
+        assert_eq!(expected, orig.ranges);
+        assert_eq!(
+            orig.to_string()
+                .parse::<DisjointRange<u8>>()
+                .unwrap()
+                .ranges,
+            orig.ranges
+        );
+        let orig: DisjointRange<i8> = "-10--5,-3,0-2".parse().unwrap();
+        let expected = vec![
+            UnaryRange { low: -10, high: -5 },
+            UnaryRange { low: -3, high: -3 },
+            UnaryRange { low: 0, high: 2 },
+        ];
+        assert_eq!(expected, orig.ranges);
+        assert!("".parse::<DisjointRange<u8>>().unwrap().ranges.is_empty());
+        assert_eq!(
+            Err(RangeError::InvertedBounds),
+            "5-1".parse::<DisjointRange<u8>>().map(|r| r.ranges)
+        );
+        for bad in ["1-300", "a", "1,,2", "1-", "-1", "1-2-3"] {
+            assert_eq!(
+                Err(RangeError::InvalidFormat),
+                bad.parse::<DisjointRange<u8>>().map(|r| r.ranges),
+                "{bad}"
+            );
+        }
     }
-}
+    #[test]
+    fn test_subtract_points() {
+        let mut orig = DisjointRange::from_bounds_unchecked([(0u8, 10), (20, 30), (250, u8::MAX)]);
+        orig.subtract_points([25, 0, 5, 6, 5, 15, 30, 20, u8::MAX, 40]);
+        let expected = vec![
+            UnaryRange { low: 1, high: 4 },
+            UnaryRange { low: 7, high: 10 },
+            UnaryRange { low: 21, high: 24 },
+            UnaryRange { low: 26, high: 29 },
+            UnaryRange {
+                low: 250,
+                high: 254,
+            },
+        ];
+        assert_eq!(expected, orig.ranges);
+        let mut orig = DisjointRange::from_bounds_unchecked([(3u8, 5), (8, 8)]);
+        orig.subtract_points([3, 4, 5, 8]);
+        assert!(orig.ranges.is_empty());
+        let mut orig = DisjointRange::from_bounds_unchecked([(3u8, 5)]);
+        orig.subtract_points([]);
+        assert_eq!(vec![UnaryRange { low: 3, high: 5 }], orig.ranges);
+    }
+    #[test]
+    fn test_as_slice() {
+        let orig = DisjointRange::from_bounds_unchecked([(20u8, 30), (0, 10)]);
+        let expected = [
+            UnaryRange { low: 0, high: 10 },
+            UnaryRange { low: 20, high: 30 },
+        ];
+        assert_eq!(&expected, orig.as_slice());
+        assert_eq!(
+            Ok(1),
+            orig.as_slice().binary_search_by(|range| range.low.cmp(&20))
+        );
+        assert!(DisjointRange::<u8>::empty().as_slice().is_empty());
+    }
+    #[test]
+    fn test_clamp_value() {
+        let orig = DisjointRange::from_bounds_unchecked([(10u8, 20), (30, 40)]);
+        assert_eq!(Some(15), orig.clamp_value(15));
+        assert_eq!(Some(30), orig.clamp_value(30));
+        assert_eq!(Some(10), orig.clamp_value(0));
+        assert_eq!(Some(30), orig.clamp_value(21));
+        assert_eq!(Some(30), orig.clamp_value(29));
+        assert_eq!(Some(40), orig.clamp_value(41));
+        assert_eq!(Some(40), orig.clamp_value(u8::MAX));
+        assert_eq!(None, DisjointRange::<u8>::empty().clamp_value(5));
+    }
+    #[test]
+    fn test_subtract_reporting() {
+        let mut orig = DisjointRange::from_bounds_unchecked([(0u8, 10), (20, 30), (40, 50)]);
+        let removed = orig.subtract_reporting(&UnaryRange::new_unchecked(5, 25));
+        let expected = vec![
+            UnaryRange { low: 5, high: 10 },
+            UnaryRange { low: 20, high: 25 },
+        ];
+        assert_eq!(expected, removed.ranges);
+        let expected = vec![
+            UnaryRange { low: 0, high: 4 },
+            UnaryRange { low: 26, high: 30 },
+            UnaryRange { low: 40, high: 50 },
+        ];
+        assert_eq!(expected, orig.ranges);
+        let removed = orig.subtract_reporting(&UnaryRange::new_unchecked(31, 39));
+        assert!(removed.ranges.is_empty());
+        assert_eq!(expected, orig.ranges);
+    }
+    #[test]
+    fn test_from_ranges_normalizes() {
+        let orig = DisjointRange::from_ranges(vec![
+            UnaryRange::new_unchecked(20u8, 25),
+            UnaryRange::new_unchecked(0, 5),
+            UnaryRange::new_unchecked(24, 30),
+            UnaryRange::new_unchecked(6, 8),
+        ]);
+        let expected = vec![
+            UnaryRange { low: 0, high: 8 },
+            UnaryRange { low: 20, high: 30 },
+        ];
+        assert_eq!(expected, orig.ranges);
+        assert!(orig.contains(&3));
+        assert!(orig.contains(&27));
+        assert!(!orig.contains(&15));
+    }
+    #[test]
+    fn test_meld_iter() {
+        use super::MeldIter;
 
-#[cfg(test)]
-mod tests {
-    use super::{DisjointRange, UnaryRange};
+        let sorted = vec![
+            UnaryRange::new_unchecked(0u8, 5),
+            UnaryRange::new_unchecked(2, 3),
+            UnaryRange::new_unchecked(6, 8),
+            UnaryRange::new_unchecked(10, 12),
+            UnaryRange::new_unchecked(250, u8::MAX),
+            UnaryRange::new_unchecked(u8::MAX, u8::MAX),
+        ];
+        let mut expected = sorted.clone();
+        DisjointRange::meld_ranges_unchecked(&mut expected);
+        assert_eq!(expected, MeldIter::new(sorted).collect::<Vec<_>>());
+        assert_eq!(0, MeldIter::new(Vec::<UnaryRange<u8>>::new()).count());
+        // works lazily on an unbounded stream
+        let stream = (0u32..).map(|i| UnaryRange::new_unchecked(i * 10, i * 10 + 4));
+        let first: Vec<_> = MeldIter::new(stream).take(2).collect();
+        assert_eq!(
+            vec![
+                UnaryRange { low: 0, high: 4 },
+                UnaryRange { low: 10, high: 14 }
+            ],
+            first
+        );
+    }
     #[test]
-    fn test_without_lower() {
-        let range = UnaryRange::new_unchecked(5, 10);
-        let wo_range = UnaryRange::new_unchecked(3, 6);
-        let actual = range.without(wo_range).unwrap();
-        assert_eq!(1, actual.len());
-        assert_eq!(7, actual[0].low);
-        assert_eq!(10, actual[0].high);
+    fn test_unary_union() {
+        let a = UnaryRange::new_unchecked(1u8, 4);
+        assert_eq!(
+            Ok(UnaryRange { low: 1, high: 8 }),
+            a.union(UnaryRange::new_unchecked(5, 8))
+        );
+        assert_eq!(
+            Ok(UnaryRange { low: 1, high: 8 }),
+            UnaryRange::new_unchecked(5, 8).union(a)
+        );
+        assert_eq!(
+            Ok(UnaryRange { low: 0, high: 10 }),
+            a.union(UnaryRange::new_unchecked(0, 10))
+        );
+        assert_eq!(Ok(a), a.union(UnaryRange::new_unchecked(2, 3)));
+        let far = UnaryRange::new_unchecked(6, 8);
+        assert_eq!(Err((a, far)), a.union(far));
+        assert_eq!(Err((a, far)), far.union(a));
+        let top = UnaryRange::new_unchecked(200, u8::MAX);
+        assert_eq!(
+            Ok(top),
+            top.union(UnaryRange::new_unchecked(u8::MAX, u8::MAX))
+        );
     }
     #[test]
-    fn test_without_higher() {
-        let range = UnaryRange::new_unchecked(5, 10);
-        let wo_range = UnaryRange::new_unchecked(8, 12);
-        let actual = range.without(wo_range).unwrap();
-        assert_eq!(1, actual.len());
-        assert_eq!(5, actual[0].low);
-        assert_eq!(7, actual[0].high);
+    #[allow(deprecated)]
+    fn test_contains_value() {
+        let orig = DisjointRange::from_bounds_unchecked([(0u8, 10), (20, 30)]);
+        for v in [0u8, 5, 10, 11, 19, 20, 30, 31] {
+            assert_eq!(orig.contains(&v), orig.contains_value(v));
+        }
     }
     #[test]
-    fn test_without_middle() {
-        let range = UnaryRange::new_unchecked(5, 10);
-        let wo_range = UnaryRange::new_unchecked(7, 8);
-        let actual = range.without(wo_range).unwrap();
-        assert_eq!(2, actual.len());
-        let actual_smaller = actual[0];
-        let actual_greater = actual[1];
-        assert_eq!(5, actual_smaller.low);
-        assert_eq!(6, actual_smaller.high);
-        assert_eq!(9, actual_greater.low);
-        assert_eq!(10, actual_greater.high);
+    fn test_unary_step_by() {
+        let range = UnaryRange::new_unchecked(0u8, 64);
+        assert_eq!(
+            vec![0, 16, 32, 48, 64],
+            range.step_by(16).collect::<Vec<_>>()
+        );
+        assert_eq!(vec![0, 30, 60], range.step_by(30).collect::<Vec<_>>());
+        assert_eq!(65, range.step_by(1).count());
+        assert_eq!(vec![0], range.step_by(100).collect::<Vec<_>>());
+        let top = UnaryRange::new_unchecked(250u8, u8::MAX);
+        assert_eq!(vec![250, 252, 254], top.step_by(2).collect::<Vec<_>>());
+        assert_eq!(vec![250, 255], top.step_by(5).collect::<Vec<_>>());
+        let chars = UnaryRange::new_unchecked('a', 'j');
+        assert_eq!("adgj", chars.step_by(3).collect::<String>());
     }
     #[test]
-    fn test_meld_ranges_overlapping() {
-        let mut ranges = vec![
-            UnaryRange::new_unchecked(0, 9),
-            UnaryRange::new_unchecked(10, 19),
+    #[should_panic(expected = "stride must be positive")]
+    fn test_unary_step_by_zero() {
+        let _ = UnaryRange::new_unchecked(0u8, 1).step_by(0);
+    }
+    #[test]
+    fn test_complement_in_place() {
+        for bounds in [
+            vec![(5u8, 10), (20, 30)],
+            vec![(0u8, 10), (20, 30)],
+            vec![(5u8, 10), (20, u8::MAX)],
+            vec![(0u8, 10), (20, u8::MAX)],
+            vec![(0u8, u8::MAX)],
+            vec![(7u8, 7)],
+            vec![],
+        ] {
+            let orig = DisjointRange::from_bounds_unchecked(bounds);
+            let mut actual = orig.clone();
+            actual.complement_in_place();
+            assert_eq!(orig.complement_ref().ranges, actual.ranges, "{orig:?}");
+            actual.complement_in_place();
+            assert_eq!(orig.ranges, actual.ranges);
+        }
+    }
+    #[test]
+    fn test_complement_in() {
+        let codes = DisjointRange::new_single_range_unchecked(0u16, 999);
+        let used = DisjointRange::from_bounds_unchecked([(0u16, 99), (500, 599), (2000, 3000)]);
+        let expected = vec![
+            UnaryRange {
+                low: 100,
+                high: 499,
+            },
+            UnaryRange {
+                low: 600,
+                high: 999,
+            },
         ];
-        DisjointRange::meld_ranges_unchecked(&mut ranges);
-        assert_eq!(1, ranges.len());
-        let UnaryRange { low, high } = ranges[0];
-        assert_eq!(0, low);
-        assert_eq!(19, high);
+        assert_eq!(expected, used.complement_in(&codes).ranges);
+        let domain = DisjointRange::from_bounds_unchecked([(0u16, 9), (50, 59), (90, 99)]);
+        let expected = vec![
+            UnaryRange { low: 0, high: 9 },
+            UnaryRange { low: 50, high: 54 },
+            UnaryRange { low: 90, high: 99 },
+        ];
+        let used = DisjointRange::new_single_range_unchecked(55u16, 60);
+        assert_eq!(expected, used.complement_in(&domain).ranges);
+        assert_eq!(
+            used.complement_ref().ranges,
+            used.complement_in(&DisjointRange::entire()).ranges
+        );
+        assert!(
+            used.complement_in(&DisjointRange::empty())
+                .ranges
+                .is_empty()
+        );
+    }
+    #[test]
+    fn test_from_sorted_disjoint_unchecked() {
+        let ranges = vec![
+            UnaryRange::new_unchecked(0u8, 5),
+            UnaryRange::new_unchecked(7, 10),
+            UnaryRange::new_unchecked(200, u8::MAX),
+        ];
+        let orig = DisjointRange::from_sorted_disjoint_unchecked(ranges.clone());
+        assert_eq!(ranges, orig.ranges);
+        assert!(orig.is_canonical());
+    }
+    #[test]
+    fn test_is_canonical() {
+        assert!(DisjointRange::<u8>::empty().is_canonical());
+        assert!(DisjointRange::<u8>::entire().is_canonical());
+        let canonical = |ranges: Vec<(u8, u8)>| {
+            DisjointRange {
+                ranges: ranges
+                    .into_iter()
+                    .map(|(low, high)| UnaryRange { low, high })
+                    .collect(),
+                mode: StepMode::Saturating,
+            }
+            .is_canonical()
+        };
+        assert!(canonical(vec![(0, 5), (7, 10)]));
+        assert!(!canonical(vec![(0, 5), (6, 10)]));
+        assert!(!canonical(vec![(0, 5), (3, 10)]));
+        assert!(!canonical(vec![(7, 10), (0, 5)]));
+        assert!(!canonical(vec![(5, 0)]));
+        assert!(!canonical(vec![(0, u8::MAX), (u8::MAX, u8::MAX)]));
+    }
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "ranges aren't sorted and melded")]
+    fn test_from_sorted_disjoint_unchecked_debug_check() {
+        DisjointRange::from_sorted_disjoint_unchecked(vec![
+            UnaryRange::new_unchecked(7u8, 10),
+            UnaryRange::new_unchecked(0, 5),
+        ]);
+    }
+    #[test]
+    fn test_signed_meld_and_complement() {
+        let orig = DisjointRange::from_bounds_unchecked([(-1i8, 5), (-128, -100)]);
+        let expected = vec![
+            UnaryRange {
+                low: -128,
+                high: -100,
+            },
+            UnaryRange { low: -1, high: 5 },
+        ];
+        assert_eq!(expected, orig.ranges);
+        let expected = vec![
+            UnaryRange { low: -99, high: -2 },
+            UnaryRange {
+                low: 6,
+                high: i8::MAX,
+            },
+        ];
+        assert_eq!(expected, orig.complement_ref().ranges);
+        assert_eq!(expected, orig.iter_complement().collect::<Vec<_>>());
+        assert_eq!(orig.ranges, orig.complement_ref().complement().ranges);
+        // melding across zero and down to `MIN_VAL`
+        let orig =
+            DisjointRange::from_bounds_unchecked([(-128i8, -100), (-99, -1), (0, 5), (7, 7)]);
+        let expected = vec![
+            UnaryRange { low: -128, high: 5 },
+            UnaryRange { low: 7, high: 7 },
+        ];
+        assert_eq!(expected, orig.ranges);
+        let expected = vec![
+            UnaryRange { low: 6, high: 6 },
+            UnaryRange {
+                low: 8,
+                high: i8::MAX,
+            },
+        ];
+        assert_eq!(expected, orig.complement_ref().ranges);
+        let orig = DisjointRange::new_single_range_unchecked(i8::MIN, i8::MIN);
+        assert_eq!(
+            vec![UnaryRange {
+                low: -127,
+                high: i8::MAX
+            }],
+            orig.complement_ref().ranges
+        );
+        let mut orig = DisjointRange::new_single_range_unchecked(i8::MIN, i8::MAX);
+        orig.subtract_unary_range(UnaryRange::new_unchecked(-1, 0));
+        let expected = vec![
+            UnaryRange {
+                low: i8::MIN,
+                high: -2,
+            },
+            UnaryRange {
+                low: 1,
+                high: i8::MAX,
+            },
+        ];
+        assert_eq!(expected, orig.ranges);
+        assert_eq!(
+            vec![UnaryRange { low: -1, high: 0 }],
+            orig.complement_ref().ranges
+        );
+    }
+    #[test]
+    fn test_nth() {
+        let orig = DisjointRange::from_bounds_unchecked([(1u8, 5), (8, 8), (12, 20)]);
+        assert_eq!(Some(1), orig.nth(0));
+        assert_eq!(Some(5), orig.nth(4));
+        assert_eq!(Some(8), orig.nth(5));
+        assert_eq!(Some(12), orig.nth(6));
+        assert_eq!(Some(20), orig.nth(14));
+        assert_eq!(None, orig.nth(15));
+        assert_eq!(None, DisjointRange::<u8>::empty().nth(0));
+    }
+    #[test]
+    fn test_quantile() {
+        let orig = DisjointRange::from_bounds_unchecked([(0u8, 9), (100, 109)]);
+        assert_eq!(Some(0), orig.quantile(0.0));
+        assert_eq!(Some(109), orig.quantile(1.0));
+        // the 20 values are at positions 0 through 19
+        assert_eq!(Some(100), orig.quantile(0.5));
+        assert_eq!(Some(108), orig.quantile(0.95));
+        assert_eq!(Some(2), orig.quantile(0.1));
+        assert_eq!(None, orig.quantile(-0.1));
+        assert_eq!(None, orig.quantile(1.5));
+        assert_eq!(None, orig.quantile(f64::NAN));
+        assert_eq!(None, DisjointRange::<u8>::empty().quantile(0.5));
+        let single = DisjointRange::new_single_range_unchecked(7u8, 7);
+        assert_eq!(Some(7), single.quantile(0.5));
+    }
+    #[test]
+    fn test_insert_range() {
+        let mut orig = DisjointRange::from_bounds_unchecked([(0u8, 10), (20, 30)]);
+        assert!(!orig.insert_range(UnaryRange::new_unchecked(2, 8)));
+        assert!(!orig.insert_range(UnaryRange::new_unchecked(20, 30)));
+        assert!(!orig.insert_range(UnaryRange::new_unchecked(0, 0)));
+        let expected = vec![
+            UnaryRange { low: 0, high: 10 },
+            UnaryRange { low: 20, high: 30 },
+        ];
+        assert_eq!(expected, orig.ranges);
+        assert!(orig.insert_range(UnaryRange::new_unchecked(5, 11)));
+        assert!(orig.insert_range(UnaryRange::new_unchecked(12, 19)));
+        assert_eq!(vec![UnaryRange { low: 0, high: 30 }], orig.ranges);
+        assert!(orig.insert_range(UnaryRange::new_unchecked(40, 40)));
+        assert!(DisjointRange::empty().insert_range(UnaryRange::new_unchecked(1u8, 1)));
+    }
+    #[test]
+    fn test_clear() {
+        let mut orig = DisjointRange::from_bounds_unchecked([(0u8, 10), (20, 30), (40, 50)]);
+        let capacity = orig.ranges.capacity();
+        orig.clear();
+        assert!(orig.ranges.is_empty());
+        assert_eq!(capacity, orig.ranges.capacity());
+        assert!(!orig.contains(&5));
+        orig.add_unary_range(UnaryRange::new_unchecked(5, 6));
+        assert_eq!(vec![UnaryRange { low: 5, high: 6 }], orig.ranges);
     }
 
     #[test]
-    fn test_meld_ranges_non_overlapping() {
-        let mut ranges = vec![
-            UnaryRange::new_unchecked(0, 9),
-            UnaryRange::new_unchecked(11, 19),
+    fn test_diff() {
+        let old = DisjointRange::from_bounds_unchecked([(0u8, 10), (20, 30), (50, 60)]);
+        let new = DisjointRange::from_bounds_unchecked([(5u8, 25), (28, 30), (40, 45)]);
+        let (added, removed) = old.diff(&new);
+        assert_eq!(
+            vec![
+                UnaryRange { low: 11, high: 19 },
+                UnaryRange { low: 40, high: 45 }
+            ],
+            added.ranges
+        );
+        assert_eq!(
+            vec![
+                UnaryRange { low: 0, high: 4 },
+                UnaryRange { low: 26, high: 27 },
+                UnaryRange { low: 50, high: 60 },
+            ],
+            removed.ranges
+        );
+        let (added, removed) = old.diff(&old);
+        assert!(added.ranges.is_empty());
+        assert!(removed.ranges.is_empty());
+        let (added, removed) = DisjointRange::empty().diff(&old);
+        assert_eq!(old.ranges, added.ranges);
+        assert!(removed.ranges.is_empty());
+    }
+
+    #[test]
+    fn test_from_predicate() {
+        let dirty = [3u16, 4, 5, 9, 12, 13];
+        let orig = DisjointRange::from_predicate(UnaryRange::new_unchecked(0, 15), |page| {
+            dirty.contains(page)
+        });
+        let expected = vec![
+            UnaryRange { low: 3, high: 5 },
+            UnaryRange { low: 9, high: 9 },
+            UnaryRange { low: 12, high: 13 },
         ];
-        DisjointRange::meld_ranges_unchecked(&mut ranges);
-        assert_eq!(2, ranges.len());
-        let UnaryRange { low, high } = ranges[0];
-        assert_eq!(0, low);
-        assert_eq!(9, high);
-        let UnaryRange { low, high } = ranges[1];
-        assert_eq!(11, low);
-        assert_eq!(19, high);
+        assert_eq!(expected, orig.ranges);
+        let orig =
+            DisjointRange::from_predicate(UnaryRange::new_unchecked(u8::MIN, u8::MAX), |_| true);
+        assert!(orig.is_entire());
+        let orig = DisjointRange::from_predicate(UnaryRange::new_unchecked(0u8, 10), |_| false);
+        assert!(orig.ranges.is_empty());
+    }
+
+    #[test]
+    fn test_is_covered_by() {
+        let requested = DisjointRange::from_bounds_unchecked([(10u8, 30), (50, 60)]);
+        let stores = [
+            DisjointRange::from_bounds_unchecked([(0u8, 15), (50, 55)]),
+            DisjointRange::from_bounds_unchecked([(12u8, 25), (56, 70)]),
+            DisjointRange::from_bounds_unchecked([(26u8, 30)]),
+        ];
+        assert!(requested.is_covered_by(stores.clone()));
+        assert!(!requested.is_covered_by(stores[..2].to_vec()));
+        assert!(!requested.is_covered_by([]));
+        assert!(DisjointRange::<u8>::empty().is_covered_by([]));
+        let halves = [
+            DisjointRange::from_bounds_unchecked([(0u8, 127)]),
+            DisjointRange::from_bounds_unchecked([(128u8, 255)]),
+        ];
+        assert!(DisjointRange::<u8>::entire().is_covered_by(halves));
+    }
+
+    #[test]
+    fn test_count_t() {
+        let orig = DisjointRange::from_bounds_unchecked([(0u32, 9), (100, 199)]);
+        assert_eq!(Some(110u32), orig.count_t());
+        let orig = DisjointRange::from_bounds_unchecked([(-100i8, -1), (0, 26)]);
+        assert_eq!(Some(127i8), orig.count_t());
+        let orig = DisjointRange::from_bounds_unchecked([(-100i8, -1), (0, 27)]);
+        assert_eq!(None, orig.count_t());
+        assert_eq!(Some(0u8), DisjointRange::<u8>::empty().count_t());
+        assert_eq!(None, DisjointRange::<u32>::entire().count_t());
+        assert_eq!(
+            Some(u32::MAX),
+            DisjointRange::new_single_range_unchecked(1u32, u32::MAX).count_t()
+        );
+        assert_eq!(None, DisjointRange::<u128>::entire().count_t());
+    }
+
+    #[test]
+    fn test_take_first_and_last() {
+        let orig = DisjointRange::from_bounds_unchecked([(0u8, 9), (20, 29), (40, 49)]);
+        let first = orig.take_first(15);
+        assert_eq!(
+            vec![
+                UnaryRange { low: 0, high: 9 },
+                UnaryRange { low: 20, high: 24 }
+            ],
+            first.ranges
+        );
+        let last = orig.take_last(15);
+        assert_eq!(
+            vec![
+                UnaryRange { low: 25, high: 29 },
+                UnaryRange { low: 40, high: 49 }
+            ],
+            last.ranges
+        );
+        assert_eq!(
+            vec![UnaryRange { low: 0, high: 9 }],
+            orig.take_first(10).ranges
+        );
+        assert_eq!(
+            vec![UnaryRange { low: 49, high: 49 }],
+            orig.take_last(1).ranges
+        );
+        assert!(orig.take_first(0).ranges.is_empty());
+        assert!(orig.take_last(0).ranges.is_empty());
+        assert_eq!(orig.ranges, orig.take_first(u128::MAX).ranges);
+        assert_eq!(orig.ranges, orig.take_last(30).ranges);
+        let entire = DisjointRange::<u128>::entire();
+        assert_eq!(
+            vec![UnaryRange {
+                low: 0,
+                high: u128::MAX - 1
+            }],
+            entire.take_first(u128::MAX).ranges
+        );
+    }
+
+    /// Stepped, but not Bounded, like an arbitrary-precision integer
+    #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    struct Unbounded(i64);
+
+    impl Stepped for Unbounded {
+        const STEP: Unbounded = Unbounded(1);
+        fn increment(&self) -> Self {
+            Unbounded(self.0 + 1)
+        }
+        fn decrement(&self) -> Self {
+            Unbounded(self.0 - 1)
+        }
+    }
+
+    #[test]
+    fn test_unbounded_domain() {
+        let range = |low, high| UnaryRange::new_unchecked(Unbounded(low), Unbounded(high));
+        let mut orig = DisjointRange::from_ranges(vec![range(10, 20), range(0, 5), range(6, 8)]);
+        assert_eq!(vec![range(0, 8), range(10, 20)], orig.ranges);
+        assert!(orig.contains(&Unbounded(15)));
+        assert!(!orig.contains(&Unbounded(9)));
+        assert_eq!(20, orig.count());
+        orig.intersect_with(&DisjointRange::from_ranges(vec![range(4, 12)]));
+        assert_eq!(vec![range(4, 8), range(10, 12)], orig.ranges);
+        orig.subtract_unary_range(range(5, 10));
+        assert_eq!(vec![range(4, 4), range(11, 12)], orig.ranges);
+        assert_eq!(
+            Some(vec![range(0, 2), range(8, 9)]),
+            range(0, 9).without(range(3, 7))
+        );
+    }
+
+    #[test]
+    fn test_unbounded_checked_constructors() {
+        let range = |low, high| UnaryRange::new_unchecked(Unbounded(low), Unbounded(high));
+        assert_eq!(
+            Some(range(-5, 5)),
+            UnaryRange::new(Unbounded(-5), Unbounded(5))
+        );
+        assert_eq!(None, UnaryRange::from_tuple((Unbounded(5), Unbounded(-5))));
+        assert_eq!(
+            Some(vec![range(0, 8)]),
+            DisjointRange::new_single_range(Unbounded(0), Unbounded(8)).map(|r| r.ranges)
+        );
+        assert_eq!(
+            Some(vec![range(0, 8), range(10, 20)]),
+            DisjointRange::from_bounds([
+                (Unbounded(10), Unbounded(20)),
+                (Unbounded(0), Unbounded(8))
+            ])
+            .map(|r| r.ranges)
+        );
+        assert_eq!(
+            Some(vec![range(0, 20)]),
+            DisjointRange::from_inclusive_ranges([
+                Unbounded(0)..=Unbounded(9),
+                Unbounded(10)..=Unbounded(20)
+            ])
+            .map(|r| r.ranges)
+        );
+        let built = crate::builder::DisjointRangeBuilder::new()
+            .add(Unbounded(0), Unbounded(20))
+            .subtract(Unbounded(5), Unbounded(10))
+            .build()
+            .unwrap();
+        assert_eq!(vec![range(0, 4), range(11, 20)], built.ranges);
+    }
+
+    /// Stepped but not `Copy`, like `num_bigint::BigInt`
+    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    struct NotCopy(i64, String);
+
+    impl Stepped for NotCopy {
+        const STEP: NotCopy = NotCopy(1, String::new());
+        fn increment(&self) -> Self {
+            NotCopy(self.0 + 1, String::new())
+        }
+        fn decrement(&self) -> Self {
+            NotCopy(self.0 - 1, String::new())
+        }
     }
+
     #[test]
-    fn test_meld_ranges_first_two() {
-        let mut ranges = vec![
-            UnaryRange::new_unchecked(0, 4),
-            UnaryRange::new_unchecked(5, 9),
-            UnaryRange::new_unchecked(11, 15),
-        ];
-        DisjointRange::meld_ranges_unchecked(&mut ranges);
-        assert_eq!(2, ranges.len());
-        let UnaryRange { low, high } = ranges[0];
-        assert_eq!(0, low);
-        assert_eq!(9, high);
-        let UnaryRange { low, high } = ranges[1];
-        assert_eq!(11, low);
-        assert_eq!(15, high);
+    fn test_not_copy() {
+        let val = |v| NotCopy(v, String::new());
+        let range = |low, high| UnaryRange::new_unchecked(val(low), val(high));
+        assert_eq!(Some(range(0, 5)), UnaryRange::new(val(0), val(5)));
+        assert_eq!(None, UnaryRange::from_tuple((val(5), val(0))));
+        assert_eq!((val(0), val(5)), range(0, 5).as_bounds());
+        assert!(range(0, 5).contains(&val(3)));
+        assert_eq!(Some(range(3, 5)), range(0, 5).intersect(&range(3, 9)));
+        assert_eq!(
+            Some(vec![range(0, 2), range(8, 9)]),
+            range(0, 9).without_ref(&range(3, 7))
+        );
+        assert_eq!(Some(vec![range(0, 9)]), range(0, 9).without(range(20, 30)));
+
+        let mut orig = DisjointRange::from_ranges(vec![range(10, 20), range(0, 5), range(6, 8)]);
+        assert_eq!(vec![range(0, 8), range(10, 20)], orig.ranges);
+        assert!(orig.contains(&val(15)));
+        assert!(!orig.contains(&val(9)));
+        assert!(orig.contains_inclusive(val(12)..=val(18)));
+        orig.intersect_with(&DisjointRange::new_single_range(val(4), val(12)).unwrap());
+        assert_eq!(vec![range(4, 8), range(10, 12)], orig.ranges);
+        assert_eq!(
+            Some(vec![range(0, 20)]),
+            DisjointRange::from_bounds([(val(10), val(20)), (val(0), val(9))]).map(|r| r.ranges)
+        );
+        assert_eq!(
+            vec![range(0, 3)],
+            DisjointRange::from_bounds_unchecked([(val(2), val(3)), (val(0), val(1))]).ranges
+        );
     }
+
     #[test]
-    fn test_meld_ranges_last_two() {
-        let mut ranges = vec![
-            UnaryRange::new_unchecked(0, 4),
-            UnaryRange::new_unchecked(6, 10),
-            UnaryRange::new_unchecked(11, 15),
-        ];
-        DisjointRange::meld_ranges_unchecked(&mut ranges);
-        assert_eq!(2, ranges.len());
-        let UnaryRange { low, high } = ranges[0];
-        assert_eq!(0, low);
-        assert_eq!(4, high);
-        let UnaryRange { low, high } = ranges[1];
-        assert_eq!(6, low);
-        assert_eq!(15, high);
+    fn test_unary_overlaps_and_intersect() {
+        let orig = UnaryRange::new_unchecked(0.5f64, 2.5);
+        assert!(orig.contains(&1.0));
+        assert!(!orig.contains(&f64::NAN));
+        assert!(orig.overlaps(&UnaryRange::new_unchecked(2.5, 3.0)));
+        assert!(!orig.overlaps(&UnaryRange::new_unchecked(2.6, 3.0)));
+        assert_eq!(
+            Some(UnaryRange {
+                low: 1.5,
+                high: 2.5
+            }),
+            orig.intersect(&UnaryRange::new_unchecked(1.5, 3.0))
+        );
+        assert_eq!(None, orig.intersect(&UnaryRange::new_unchecked(3.0, 4.0)));
+        let orig = UnaryRange::new_unchecked(3u8, 7);
+        assert_eq!(
+            Some(orig),
+            orig.intersect(&UnaryRange::new_unchecked(0, 10))
+        );
     }
+
     #[test]
-    fn test_meld_ranges_all() {
-        let mut ranges = vec![
-            UnaryRange::new_unchecked(0, 4),
-            UnaryRange::new_unchecked(5, 10),
-            UnaryRange::new_unchecked(11, 15),
-        ];
-        DisjointRange::meld_ranges_unchecked(&mut ranges);
-        assert_eq!(1, ranges.len());
-        let UnaryRange { low, high } = ranges[0];
-        assert_eq!(0, low);
-        assert_eq!(15, high);
+    fn test_comparisons_without_stepped() {
+        // `bool` isn't `Stepped`, so neither are these pairs
+        let range = |low, high| UnaryRange::new_unchecked(low, high);
+        let mut orig = DisjointRange {
+            ranges: vec![range((0u8, false), (2, true)), range((5, true), (7, false))],
+            mode: StepMode::Saturating,
+        };
+        assert!(orig.contains(&(1, false)));
+        assert!(!orig.contains(&(5, false)));
+        assert_eq!(Some(&orig.ranges[1]), orig.range_containing(&(6, true)));
+        let other = DisjointRange {
+            ranges: vec![range((2, true), (5, true))],
+            mode: StepMode::Saturating,
+        };
+        assert!(orig.overlaps(&other));
+        orig.intersect_with(&other);
+        assert_eq!(
+            vec![range((2, true), (2, true)), range((5, true), (5, true))],
+            orig.ranges
+        );
+        let other = DisjointRange {
+            ranges: vec![range((3, false), (5, false))],
+            mode: StepMode::Saturating,
+        };
+        assert!(!orig.overlaps(&other));
+        assert!(!orig.overlaps(&DisjointRange {
+            ranges: vec![],
+            mode: StepMode::Saturating
+        }));
     }
+
     #[test]
-    fn test_subtract_range_lowest_lower() {
-        let mut orig = DisjointRange::from_bounds_unchecked(vec![(0, 4), (6, 10), (12, 16)]);
-        let o2 = orig.clone();
-        let wo_range = UnaryRange::new_unchecked(0, 2);
-        orig.subtract_unary_range(wo_range);
-        assert_eq!(3, orig.ranges.len());
-        assert_eq!(UnaryRange { low: 3, high: 4 }, orig.ranges[0]);
-        assert_eq!(o2.ranges[1], orig.ranges[1]);
-        assert_eq!(o2.ranges[2], orig.ranges[2]);
+    fn test_iter_offsets() {
+        let orig = DisjointRange::from_bounds_unchecked([(4096u64, 8191), (16384, 16384)]);
+        assert_eq!(
+            vec![(4096, 4096), (16384, 1)],
+            orig.iter_offsets().collect::<Vec<_>>()
+        );
+        assert_eq!(None, DisjointRange::<u8>::empty().iter_offsets().next());
+        let entire = DisjointRange::<u64>::entire();
+        assert_eq!(
+            vec![(0, u64::MAX as u128 + 1)],
+            entire.iter_offsets().collect::<Vec<_>>()
+        );
     }
+
     #[test]
-    fn test_subtract_unary_range_lowest_higher() {
-        let mut orig = DisjointRange::from_bounds_unchecked(vec![(0, 4), (6, 10), (12, 16)]);
-        let o2 = orig.clone();
-        let wo_range = UnaryRange::new_unchecked(2, 4);
-        orig.subtract_unary_range(wo_range);
-        assert_eq!(3, orig.ranges.len());
-        assert_eq!(UnaryRange { low: 0, high: 1 }, orig.ranges[0]);
-        assert_eq!(o2.ranges[1], orig.ranges[1]);
-        assert_eq!(o2.ranges[2], orig.ranges[2]);
+    fn test_contains_converted() {
+        let orig = DisjointRange::from_bounds_unchecked([(80u16, 80), (8000, u16::MAX)]);
+        assert!(orig.contains_converted(80u32));
+        assert!(orig.contains_converted(65535u32));
+        assert!(!orig.contains_converted(65536u32));
+        assert!(!orig.contains_converted(81u64));
+        assert!(!orig.contains_converted(-1i32));
+        assert!(orig.contains_converted(80u8));
     }
+
     #[test]
-    fn test_subtract_unary_range_lowest_middle() {
-        let mut orig = DisjointRange::from_bounds_unchecked(vec![(0, 4), (6, 10), (12, 16)]);
-        let o2 = orig.clone();
-        let wo_range = UnaryRange::new_unchecked(2, 3);
-        orig.subtract_unary_range(wo_range);
-        assert_eq!(4, orig.ranges.len());
-        assert_eq!(UnaryRange { low: 0, high: 1 }, orig.ranges[0]);
-        assert_eq!(UnaryRange { low: 4, high: 4 }, orig.ranges[1]);
-        assert_eq!(o2.ranges[1], orig.ranges[2]);
-        assert_eq!(o2.ranges[2], orig.ranges[3]);
+    fn test_as_slice_ordering() {
+        let mut orig = DisjointRange::from_ranges(vec![
+            UnaryRange::new_unchecked(50u8, 60),
+            UnaryRange::new_unchecked(0, 10),
+        ]);
+        orig.add_unary_range(UnaryRange::new_unchecked(30, 40));
+        orig.subtract_unary_range(UnaryRange::new_unchecked(35, 35));
+        orig.union_with(&DisjointRange::from_bounds_unchecked([
+            (11u8, 12),
+            (200, 210),
+        ]));
+        let bounds: Vec<(u8, u8)> = orig.as_slice().iter().map(UnaryRange::as_bounds).collect();
+        assert_eq!(
+            vec![(0, 12), (30, 34), (36, 40), (50, 60), (200, 210)],
+            bounds
+        );
     }
+
     #[test]
-    fn test_subtract_unary_range_lower_spanning() {
-        let mut orig = DisjointRange::from_bounds_unchecked(vec![(0, 4), (6, 10), (12, 16)]);
-        let o2 = orig.clone();
-        let wo_range = UnaryRange::new_unchecked(4, 7);
-        orig.subtract_unary_range(wo_range);
-        assert_eq!(3, orig.ranges.len());
-        assert_eq!(UnaryRange { low: 0, high: 3 }, orig.ranges[0]);
-        assert_eq!(UnaryRange { low: 8, high: 10 }, orig.ranges[1]);
-        assert_eq!(o2.ranges[2], orig.ranges[2]);
+    fn test_add_unary_range_after_unsorted_from_ranges() {
+        let mut orig = DisjointRange::from_ranges(vec![
+            UnaryRange::new_unchecked(50u8, 60),
+            UnaryRange::new_unchecked(0, 10),
+            UnaryRange::new_unchecked(20, 30),
+        ]);
+        orig.add_unary_range(UnaryRange::new_unchecked(11, 19));
+        orig.add_unary_range(UnaryRange::new_unchecked(45, 47));
+        let expected = vec![
+            UnaryRange { low: 0, high: 30 },
+            UnaryRange { low: 45, high: 47 },
+            UnaryRange { low: 50, high: 60 },
+        ];
+        assert_eq!(expected, orig.ranges);
+        orig.add_unary_range(UnaryRange::new_unchecked(40, 200));
+        assert_eq!(
+            vec![
+                UnaryRange { low: 0, high: 30 },
+                UnaryRange { low: 40, high: 200 }
+            ],
+            orig.ranges
+        );
     }
+
+    #[cfg(debug_assertions)]
     #[test]
-    fn test_add_unary_range_before_separate() {
-        let mut orig = DisjointRange::from_bounds_unchecked(vec![(4, 6), (8, 10)]);
-        let o2 = orig.clone();
-        let to_add = UnaryRange::new_unchecked(0, 2);
-        let ta = to_add.clone();
-        orig.add_unary_range(to_add);
-        assert_eq!(3, orig.ranges.len());
-        assert_eq!(ta, orig.ranges[0]);
-        assert_eq!(o2.ranges[0], orig.ranges[1]);
-        assert_eq!(o2.ranges[1], orig.ranges[2]);
+    #[should_panic(expected = "ranges aren't sorted and melded")]
+    fn test_add_unary_range_requires_canonical() {
+        let mut orig = DisjointRange {
+            ranges: vec![
+                UnaryRange::new_unchecked(50u8, 60),
+                UnaryRange::new_unchecked(0, 10),
+            ],
+            mode: StepMode::Saturating,
+        };
+        orig.add_unary_range(UnaryRange::new_unchecked(20, 30));
     }
+
     #[test]
-    fn test_add_unary_range_before_merged() {
-        let mut orig = DisjointRange::from_bounds_unchecked(vec![(4, 6), (8, 10)]);
-        let o2 = orig.clone();
-        let to_add = UnaryRange::new_unchecked(0, 3);
-        orig.add_unary_range(to_add);
-        assert_eq!(2, orig.ranges.len());
-        assert_eq!(UnaryRange { low: 0, high: 6 }, orig.ranges[0]);
-        assert_eq!(o2.ranges[1], orig.ranges[1]);
+    fn test_normalize_reporting() {
+        let (orig, merged) = DisjointRange::normalize_reporting(vec![
+            UnaryRange::new_unchecked(20u8, 30),
+            UnaryRange::new_unchecked(0, 10),
+            UnaryRange::new_unchecked(25, 40),
+            UnaryRange::new_unchecked(50, 60),
+            UnaryRange::new_unchecked(41, 45),
+            UnaryRange::new_unchecked(5, 8),
+        ]);
+        let expected = vec![
+            UnaryRange { low: 0, high: 10 },
+            UnaryRange { low: 20, high: 45 },
+            UnaryRange { low: 50, high: 60 },
+        ];
+        assert_eq!(expected, orig.ranges);
+        assert_eq!(vec![(0, 2), (1, 5), (2, 4)], merged);
+        let (orig, merged) = DisjointRange::normalize_reporting(vec![
+            UnaryRange::new_unchecked(0u8, 10),
+            UnaryRange::new_unchecked(20, 30),
+        ]);
+        assert_eq!(2, orig.num_ranges());
+        assert!(merged.is_empty());
+        let (orig, merged) = DisjointRange::<u8>::normalize_reporting(vec![]);
+        assert!(orig.ranges.is_empty());
+        assert!(merged.is_empty());
     }
+
     #[test]
-    fn test_add_unary_range_middle_separate() {
-        let mut orig = DisjointRange::from_bounds_unchecked(vec![(4, 5), (10, 11)]);
-        let o2 = orig.clone();
-        let to_add = UnaryRange::new_unchecked(7, 8);
-        let ta = to_add.clone();
-        orig.add_unary_range(to_add);
-        assert_eq!(3, orig.ranges.len());
-        assert_eq!(o2.ranges[0], orig.ranges[0]);
-        assert_eq!(ta, orig.ranges[1]);
-        assert_eq!(o2.ranges[1], orig.ranges[2]);
+    fn test_new_exclusive() {
+        assert_eq!(
+            Some(UnaryRange { low: 0u8, high: 9 }),
+            UnaryRange::new_exclusive(0, 10)
+        );
+        assert_eq!(
+            Some(UnaryRange { low: 5u8, high: 5 }),
+            UnaryRange::new_exclusive(5, 6)
+        );
+        assert_eq!(None, UnaryRange::new_exclusive(5u8, 5));
+        assert_eq!(None, UnaryRange::new_exclusive(6u8, 5));
+        assert_eq!(None, UnaryRange::new_exclusive(u8::MIN, u8::MIN));
+        assert_eq!(
+            Some(UnaryRange {
+                low: -3i8,
+                high: -2
+            }),
+            UnaryRange::new_exclusive(-3, -1)
+        );
     }
+
     #[test]
-    fn test_add_unary_range_middle_merged() {
-        let mut orig = DisjointRange::from_bounds_unchecked(vec![(4, 5), (10, 11)]);
-        let o2 = orig.clone();
-        let to_add = UnaryRange::new_unchecked(6, 8);
-        orig.add_unary_range(to_add);
-        assert_eq!(2, orig.ranges.len());
-        assert_eq!(UnaryRange { low: 4, high: 8 }, orig.ranges[0]);
-        assert_eq!(o2.ranges[1], orig.ranges[1]);
+    fn test_covers_same() {
+        let canonical = DisjointRange::from_bounds_unchecked([(0u8, 10), (20, 30)]);
+        let messy = DisjointRange {
+            ranges: vec![
+                UnaryRange::new_unchecked(20, 25),
+                UnaryRange::new_unchecked(0, 4),
+                UnaryRange::new_unchecked(40, 35),
+                UnaryRange::new_unchecked(5, 10),
+                UnaryRange::new_unchecked(24, 30),
+            ],
+            mode: StepMode::Saturating,
+        };
+        assert!(canonical.covers_same(&messy));
+        assert!(messy.covers_same(&canonical));
+        assert!(canonical.covers_same(&canonical.clone()));
+        let other = DisjointRange::from_bounds_unchecked([(0u8, 10), (20, 31)]);
+        assert!(!canonical.covers_same(&other));
+        assert!(!messy.covers_same(&other));
+        assert!(DisjointRange::<u8>::empty().covers_same(&DisjointRange {
+            ranges: vec![UnaryRange::new_unchecked(5, 4)],
+            mode: StepMode::Saturating
+        }));
     }
+
     #[test]
-    fn test_add_unary_range_merge_all() {
-        let mut orig = DisjointRange::from_bounds_unchecked(vec![(4, 5), (10, 11)]);
-        let to_add = UnaryRange::new_unchecked(6, 9);
-        orig.add_unary_range(to_add);
-        assert_eq!(1, orig.ranges.len());
-        assert_eq!(UnaryRange { low: 4, high: 11 }, orig.ranges[0]);
+    fn test_contains_inclusive() {
+        let orig = DisjointRange::from_bounds_unchecked([(0u8, 10), (20, 30)]);
+        assert!(orig.contains_inclusive(2..=8));
+        assert!(orig.contains_inclusive(20..=30));
+        assert!(orig.contains_inclusive(5..=5));
+        assert!(!orig.contains_inclusive(5..=20));
+        assert!(!orig.contains_inclusive(15..=16));
+        assert!(!orig.contains_inclusive(25..=31));
+        assert!(!orig.contains_inclusive(std::ops::RangeInclusive::new(8, 2)));
     }
+
     #[test]
-    fn test_complement_unary() {
-        let orig = UnaryRange::new_unchecked(10u8, 50u8);
-        let complement = orig.complement().unwrap();
-        assert_eq!(2, complement.ranges.len());
-        assert_eq!(UnaryRange { low: 0, high: 9 }, complement.ranges[0]);
+    fn test_complement_many_ranges() {
+        let evens = DisjointRange::from_ranges(
+            (0..=1000u16)
+                .map(|n| UnaryRange::new_unchecked(n * 2, n * 2))
+                .collect(),
+        );
+        let odds = evens.complement_ref();
+        assert_eq!(1001, odds.num_ranges());
+        assert_eq!(UnaryRange { low: 1, high: 1 }, odds.ranges[0]);
         assert_eq!(
             UnaryRange {
-                low: 51,
-                high: u8::MAX
+                low: 1999,
+                high: 1999
             },
-            complement.ranges[1]
+            odds.ranges[999]
         );
-    }
-    #[test]
-    fn test_complement_disjoint() {
-        let orig = DisjointRange::from_bounds_unchecked([(10u8, 50), (70, 100)]);
-        let complement = orig.complement();
-        assert_eq!(3, complement.ranges.len());
-        assert_eq!(UnaryRange { low: 0, high: 9 }, complement.ranges[0]);
-        assert_eq!(UnaryRange { low: 51, high: 69 }, complement.ranges[1]);
         assert_eq!(
             UnaryRange {
-                low: 101,
-                high: u8::MAX
+                low: 2001,
+                high: u16::MAX
             },
-            complement.ranges[2]
+            odds.ranges[1000]
         );
+        assert_eq!(odds.ranges, evens.clone().complement().ranges);
+        assert_eq!(evens.ranges, odds.complement().ranges);
+    }
+}
+
+#[cfg(test)]
+mod proptests {
+    use super::{DisjointRange, Membership, UnaryRange};
+    use proptest::prelude::*;
+    use std::collections::HashSet;
+
+    const DOMAIN_LOW: i32 = -40;
+    const DOMAIN_HIGH: i32 = 40;
+
+    /// Values to check membership of: everything in the domain the ranges are drawn
+    /// from, plus a margin and the type's bounds
+    fn probes() -> impl Iterator<Item = i32> {
+        (DOMAIN_LOW - 2..=DOMAIN_HIGH + 2).chain([i32::MIN, i32::MIN + 1, i32::MAX - 1, i32::MAX])
+    }
+
+    fn value() -> impl Strategy<Value = i32> {
+        prop_oneof![
+            8 => DOMAIN_LOW..=DOMAIN_HIGH,
+            1 => Just(i32::MIN),
+            1 => Just(i32::MAX),
+        ]
+    }
+
+    fn unary_range() -> impl Strategy<Value = UnaryRange<i32>> {
+        (value(), value()).prop_map(|(a, b)| UnaryRange::new_unchecked(a.min(b), a.max(b)))
+    }
+
+    fn disjoint_range() -> impl Strategy<Value = DisjointRange<i32>> {
+        prop::collection::vec(unary_range(), 0..6).prop_map(|mut ranges| {
+            DisjointRange::meld_ranges(&mut ranges);
+            DisjointRange::from_ranges(ranges)
+        })
+    }
+
+    fn model(range: &DisjointRange<i32>) -> HashSet<i32> {
+        probes()
+            .filter(|v| range.ranges.iter().any(|r| r.low <= *v && *v <= r.high))
+            .collect()
+    }
+
+    fn assert_canonical(range: &DisjointRange<i32>) {
+        assert!(range.is_canonical(), "{range:?} isn't canonical");
+        for pair in range.ranges.windows(2) {
+            assert!(pair[0].low <= pair[0].high);
+            assert!(pair[0].high < pair[1].low);
+            assert!(
+                pair[0].high.saturating_add(1) < pair[1].low,
+                "{range:?} isn't melded"
+            );
+        }
+        if let Some(last) = range.ranges.last() {
+            assert!(last.low <= last.high);
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    enum Op {
+        Add(UnaryRange<i32>),
+        Subtract(UnaryRange<i32>),
+        AddDisjoint(DisjointRange<i32>),
+    }
+
+    fn op() -> impl Strategy<Value = Op> {
+        prop_oneof![
+            unary_range().prop_map(Op::Add),
+            unary_range().prop_map(Op::Subtract),
+            disjoint_range().prop_map(Op::AddDisjoint),
+        ]
+    }
+
+    fn union(a: &DisjointRange<i32>, b: &DisjointRange<i32>) -> DisjointRange<i32> {
+        let mut out = a.clone();
+        out.union_with(b);
+        out
+    }
+
+    fn intersection(a: &DisjointRange<i32>, b: &DisjointRange<i32>) -> DisjointRange<i32> {
+        let mut out = a.clone();
+        out.intersect_with(b);
+        out
+    }
+
+    proptest! {
+        #[test]
+        fn test_contains_agrees_with_model(a in disjoint_range()) {
+            let model = model(&a);
+            for v in probes() {
+                prop_assert_eq!(model.contains(&v), a.contains(&v), "{:?} {}", a, v);
+            }
+        }
+
+        #[test]
+        fn test_double_complement(a in disjoint_range()) {
+            let complement = a.complement_ref();
+            assert_canonical(&complement);
+            prop_assert_eq!(&a.ranges, &complement.complement().ranges);
+        }
+
+        #[test]
+        fn test_iter_complement_matches_complement(a in disjoint_range()) {
+            let lazy: Vec<UnaryRange<i32>> = a.iter_complement().collect();
+            prop_assert_eq!(&a.complement_ref().ranges, &lazy);
+        }
+
+        #[test]
+        fn test_complement_in_place_matches_complement(a in disjoint_range()) {
+            let mut actual = a.clone();
+            actual.complement_in_place();
+            prop_assert_eq!(&a.complement_ref().ranges, &actual.ranges);
+        }
+
+        #[test]
+        fn test_complement_partitions_entire(a in disjoint_range()) {
+            let complement = a.complement_ref();
+            prop_assert!(union(&a, &complement).is_entire());
+            prop_assert!(intersection(&a, &complement).ranges.is_empty());
+            for v in probes() {
+                prop_assert_ne!(a.contains(&v), complement.contains(&v));
+            }
+        }
+
+        #[test]
+        fn test_de_morgan(a in disjoint_range(), b in disjoint_range()) {
+            let union_complement = union(&a, &b).complement();
+            let complement_intersection = intersection(&a.complement_ref(), &b.complement_ref());
+            prop_assert_eq!(&union_complement.ranges, &complement_intersection.ranges);
+            let intersection_complement = intersection(&a, &b).complement();
+            let complement_union = union(&a.complement_ref(), &b.complement_ref());
+            prop_assert_eq!(&intersection_complement.ranges, &complement_union.ranges);
+        }
+
+        #[test]
+        fn test_set_operations_agree_with_model(a in disjoint_range(), b in disjoint_range()) {
+            let (model_a, model_b) = (model(&a), model(&b));
+            let actual = union(&a, &b);
+            assert_canonical(&actual);
+            prop_assert_eq!(model(&actual), &model_a | &model_b);
+            let actual = intersection(&a, &b);
+            assert_canonical(&actual);
+            prop_assert_eq!(model(&actual), &model_a & &model_b);
+            let mut actual = a.clone();
+            actual.difference_with(&b);
+            assert_canonical(&actual);
+            prop_assert_eq!(model(&actual), &model_a - &model_b);
+        }
+
+        #[test]
+        fn test_unary_operations_agree_with_model(a in disjoint_range(), r in unary_range()) {
+            let model_a = model(&a);
+            let model_r = model(&DisjointRange::from_ranges(vec![r]));
+            let mut actual = a.clone();
+            actual.add_unary_range(r);
+            assert_canonical(&actual);
+            prop_assert_eq!(model(&actual), &model_a | &model_r);
+            let mut actual = a.clone();
+            actual.subtract_unary_range(r);
+            assert_canonical(&actual);
+            prop_assert_eq!(model(&actual), &model_a - &model_r);
+        }
+
+        #[test]
+        fn test_subtract_points_agrees_with_model(
+            a in disjoint_range(),
+            points in prop::collection::vec(value(), 0..20),
+        ) {
+            let mut actual = a.clone();
+            actual.subtract_points(points.iter().copied());
+            assert_canonical(&actual);
+            let points: HashSet<i32> = points.into_iter().collect();
+            prop_assert_eq!(model(&actual), &model(&a) - &points);
+        }
+
+        #[test]
+        fn test_overlay_agrees_with_model(a in disjoint_range(), b in disjoint_range()) {
+            let (model_a, model_b) = (model(&a), model(&b));
+            let segments = a.overlay(&b);
+            for pair in segments.windows(2) {
+                prop_assert!(pair[0].0.high < pair[1].0.low);
+                if pair[0].1 == pair[1].1 {
+                    prop_assert!(pair[0].0.high.saturating_add(1) < pair[1].0.low);
+                }
+            }
+            for v in probes() {
+                let expected = match (model_a.contains(&v), model_b.contains(&v)) {
+                    (true, true) => Some(Membership::Both),
+                    (true, false) => Some(Membership::OnlyLeft),
+                    (false, true) => Some(Membership::OnlyRight),
+                    (false, false) => None,
+                };
+                let actual = segments
+                    .iter()
+                    .find(|(range, _)| range.contains(&v))
+                    .map(|(_, membership)| *membership);
+                prop_assert_eq!(expected, actual);
+            }
+        }
+
+        #[test]
+        fn test_diff_agrees_with_model(a in disjoint_range(), b in disjoint_range()) {
+            let (added, removed) = a.diff(&b);
+            assert_canonical(&added);
+            assert_canonical(&removed);
+            let (model_a, model_b) = (model(&a), model(&b));
+            prop_assert_eq!(model(&added), &model_b - &model_a);
+            prop_assert_eq!(model(&removed), &model_a - &model_b);
+        }
+
+        #[test]
+        fn test_is_covered_by_agrees_with_model(
+            a in disjoint_range(),
+            sets in prop::collection::vec(disjoint_range(), 0..4),
+        ) {
+            let union: HashSet<i32> = sets.iter().flat_map(model).collect();
+            prop_assert_eq!(model(&a).is_subset(&union), a.is_covered_by(sets));
+        }
+
+        #[test]
+        fn test_overlaps_agrees_with_model(a in disjoint_range(), b in disjoint_range()) {
+            let (model_a, model_b) = (model(&a), model(&b));
+            prop_assert_eq!(!model_a.is_disjoint(&model_b), a.overlaps(&b));
+        }
+
+        #[test]
+        #[allow(deprecated)]
+        fn test_ordering_guarantee(ops in prop::collection::vec(op(), 0..12)) {
+            let mut actual = DisjointRange::empty();
+            for op in ops {
+                match op {
+                    Op::Add(range) => actual.add_unary_range(range),
+                    Op::Subtract(range) => actual.subtract_unary_range(range),
+                    Op::AddDisjoint(other) => actual.add_disjoint_range(other),
+                }
+                for pair in actual.as_slice().windows(2) {
+                    let (first, second) = (pair[0].as_bounds(), pair[1].as_bounds());
+                    prop_assert!(first.0 <= first.1 && second.0 <= second.1);
+                    prop_assert!(first.1.saturating_add(1) < second.0, "{:?}", actual);
+                }
+            }
+        }
+
+        #[test]
+        fn test_normalize_reporting_matches_from_ranges(
+            ranges in prop::collection::vec(unary_range(), 0..8),
+        ) {
+            let (actual, merged) = DisjointRange::normalize_reporting(ranges.clone());
+            assert_canonical(&actual);
+            prop_assert_eq!(&DisjointRange::from_ranges(ranges.clone()).ranges, &actual.ranges);
+            prop_assert_eq!(ranges.len() - actual.ranges.len(), merged.len());
+            for (i, j) in merged {
+                prop_assert!(i < j);
+                let (a, b) = (ranges[i], ranges[j]);
+                prop_assert!(a.low.max(b.low) <= a.high.min(b.high).saturating_add(1));
+            }
+        }
     }
 }
@@ -3,8 +3,13 @@
 //! [`Bounded`] and [`Stepped`] are already [implemented for standard numeric types](crate::impls).
 //! You're obviously welcome to implement these traits for your own custom types, but given that
 //! [`UnaryRange`](crate::ranges::UnaryRange) and [`DisjointRange`](crate::ranges::DisjointRange)
-//! both require `T: Copy + Clone + Bounded + Stepped` and `DisjointRange` additionally requires
-//! `T: Ord`, the space of available (distinct, meaningful) types is a bit limited. Maybe tuples?
+//! mostly require `T: Copy + Clone + Stepped` (plus [`Bounded`] for complements, though
+//! construction and a few basic operations only need `T: Clone`) and `DisjointRange`
+//! additionally requires `T: Ord`, the space of available (distinct, meaningful) types is a bit
+//! limited. Pairs of
+//! such types [work too](crate::impls), ordered lexicographically.
+
+use std::cmp::Ordering;
 
 /// Trait for types with minimum and maximum values
 ///
@@ -20,13 +25,146 @@ pub trait Bounded: PartialOrd {
 /// For any `v: impl Stepped`:
 /// `v.decrement() <= v <= v.increment()`
 /// `v.increment().decrement() == v.decrement().increment() == v`
-pub trait Stepped: Bounded {
+///
+/// [`Stepped`] doesn't require [`Bounded`], so types without fixed bounds (e.g. arbitrary-precision
+/// integers, which never saturate) can still be stepped. Most of
+/// [`UnaryRange`](crate::ranges::UnaryRange) and [`DisjointRange`](crate::ranges::DisjointRange)
+/// works with those too; only the operations that need the whole domain, like complements,
+/// require [`Bounded`] as well.
+///
+/// For [`Bounded`] types, stepping is expected to saturate at the bounds, so
+/// `T::MAX_VAL.increment() == T::MAX_VAL` and `T::MIN_VAL.decrement() == T::MIN_VAL`. That means
+/// the second identity only holds for values other than `T::MIN_VAL` and `T::MAX_VAL`, e.g.
+/// `u8::MAX.increment().decrement()` is `u8::MAX - 1`. For those other values, the first identity
+/// holds strictly: `v.decrement() < v < v.increment()`.
+///
+/// By default, [`DisjointRange`](crate::ranges::DisjointRange)s rely on that saturation to
+/// find `T::MIN_VAL` and `T::MAX_VAL` when melding and taking complements. Ranges using
+/// [`StepMode::Checked`](crate::ranges::StepMode::Checked) use
+/// [`Stepped::checked_increment`] and [`Stepped::checked_decrement`] instead, so they're
+/// exact at the edges of the domain even if stepping doesn't saturate.
+pub trait Stepped: PartialOrd {
     const STEP: Self;
     /// Increase by [`Stepped::STEP`]
     fn increment(&self) -> Self;
 
     /// Decrease by [`Stepped::STEP`]
     fn decrement(&self) -> Self;
+
+    /// [Increase](Stepped::increment) by [`Stepped::STEP`], or `None` at [`Bounded::MAX_VAL`]
+    /// instead of saturating
+    ///
+    /// The default implementation returns `None` if incrementing doesn't actually increase
+    /// `self`, so it doesn't need [`Bounded`].
+    fn checked_increment(&self) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        let next = self.increment();
+        (next > *self).then_some(next)
+    }
+
+    /// [Decrease](Stepped::decrement) by [`Stepped::STEP`], or `None` at [`Bounded::MIN_VAL`]
+    /// instead of saturating
+    ///
+    /// The default implementation returns `None` if decrementing doesn't actually decrease
+    /// `self`, so it doesn't need [`Bounded`].
+    fn checked_decrement(&self) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        let next = self.decrement();
+        (next < *self).then_some(next)
+    }
+
+    /// Sanity-check this type's implementation in debug builds
+    ///
+    /// Asserts that stepping away from the bounds actually moves, i.e. that
+    /// `T::MIN_VAL.increment() > T::MIN_VAL` and `T::MAX_VAL.decrement() < T::MAX_VAL`.
+    /// [`UnaryRange::new_validated`](crate::ranges::UnaryRange::new_validated),
+    /// [`DisjointRange::entire`](crate::ranges::DisjointRange::entire) and the complements
+    /// call this, so a broken impl panics early instead of producing nonsense. The checks
+    /// use `debug_assert!`, so this does nothing in release builds.
+    fn validate()
+    where
+        Self: Sized + Bounded,
+    {
+        debug_assert!(
+            Self::MIN_VAL.increment() > Self::MIN_VAL,
+            "incrementing {}::MIN_VAL doesn't increase it",
+            std::any::type_name::<Self>()
+        );
+        debug_assert!(
+            Self::MAX_VAL.decrement() < Self::MAX_VAL,
+            "decrementing {}::MAX_VAL doesn't decrease it",
+            std::any::type_name::<Self>()
+        );
+    }
+
+    /// The number of [steps](Stepped::STEP) it takes to get from `self` to `other`,
+    /// or `None` if `other < self` (or the two can't be compared)
+    ///
+    /// The default implementation repeatedly [increments](Stepped::increment) `self`,
+    /// so implementors should override it if they can do better.
+    fn steps_between(&self, other: &Self) -> Option<u128>
+    where
+        Self: Sized,
+    {
+        match self.partial_cmp(other) {
+            Some(Ordering::Less) => {}
+            Some(Ordering::Equal) => return Some(0),
+            _ => return None,
+        }
+        let mut steps: u128 = 1;
+        let mut cur = self.increment();
+        while cur < *other {
+            let next = cur.increment();
+            if next <= cur {
+                break;
+            }
+            cur = next;
+            steps = steps.saturating_add(1);
+        }
+        Some(steps)
+    }
+
+    /// [Increment](Stepped::increment) `n` times at once, saturating at [`Bounded::MAX_VAL`]
+    ///
+    /// The default implementation repeatedly increments `self` (stopping early once it
+    /// stops moving), so implementors should override it if they can do better.
+    fn add_steps(&self, n: u128) -> Self
+    where
+        Self: Sized + Copy,
+    {
+        let mut cur = *self;
+        for _ in 0..n {
+            let next = cur.increment();
+            if next <= cur {
+                break;
+            }
+            cur = next;
+        }
+        cur
+    }
+
+    /// [Decrement](Stepped::decrement) `n` times at once, saturating at [`Bounded::MIN_VAL`]
+    ///
+    /// The default implementation repeatedly decrements `self` (stopping early once it
+    /// stops moving), so implementors should override it if they can do better.
+    fn sub_steps(&self, n: u128) -> Self
+    where
+        Self: Sized + Copy,
+    {
+        let mut cur = *self;
+        for _ in 0..n {
+            let next = cur.decrement();
+            if next >= cur {
+                break;
+            }
+            cur = next;
+        }
+        cur
+    }
 }
 
 /// Helper function providing a type's [`Bounded::MIN_VAL`]
@@ -38,3 +176,114 @@ pub fn bounded_min<T: Bounded>() -> T {
 pub fn bounded_max<T: Bounded>() -> T {
     T::MAX_VAL
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Bounded, Stepped};
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct Tens(u8);
+
+    impl Bounded for Tens {
+        const MIN_VAL: Tens = Tens(0);
+        const MAX_VAL: Tens = Tens(250);
+    }
+
+    impl Stepped for Tens {
+        const STEP: Tens = Tens(10);
+        fn increment(&self) -> Self {
+            Tens(self.0.saturating_add(10).min(250))
+        }
+        fn decrement(&self) -> Self {
+            Tens(self.0.saturating_sub(10))
+        }
+    }
+
+    #[test]
+    fn test_default_steps_between() {
+        assert_eq!(Some(0), Tens(20).steps_between(&Tens(20)));
+        assert_eq!(Some(3), Tens(20).steps_between(&Tens(50)));
+        assert_eq!(Some(25), Tens::MIN_VAL.steps_between(&Tens::MAX_VAL));
+        assert_eq!(None, Tens(50).steps_between(&Tens(20)));
+    }
+
+    #[test]
+    fn test_default_add_steps() {
+        assert_eq!(Tens(50), Tens(20).add_steps(3));
+        assert_eq!(Tens(20), Tens(50).sub_steps(3));
+        assert_eq!(Tens(20), Tens(20).add_steps(0));
+        assert_eq!(Tens::MAX_VAL, Tens(20).add_steps(1000));
+        assert_eq!(Tens::MIN_VAL, Tens(20).sub_steps(1000));
+    }
+
+    #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    struct Stuck(u8);
+
+    impl Bounded for Stuck {
+        const MIN_VAL: Stuck = Stuck(0);
+        const MAX_VAL: Stuck = Stuck(10);
+    }
+
+    impl Stepped for Stuck {
+        const STEP: Stuck = Stuck(1);
+        // never moves away from `MIN_VAL`
+        fn increment(&self) -> Self {
+            Stuck(if self.0 == 0 { 0 } else { (self.0 + 1).min(10) })
+        }
+        fn decrement(&self) -> Self {
+            Stuck(self.0.saturating_sub(1))
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        Tens::validate();
+        u8::validate();
+        i128::validate();
+        char::validate();
+        f32::validate();
+        f64::validate();
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "MIN_VAL doesn't increase it")]
+    fn test_validate_broken() {
+        Stuck::validate();
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "MIN_VAL doesn't increase it")]
+    fn test_new_validated() {
+        crate::ranges::UnaryRange::new_validated(Stuck(1), Stuck(5));
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "MIN_VAL doesn't increase it")]
+    fn test_complement_validates() {
+        let range = crate::ranges::DisjointRange::new_single_range(Stuck(1), Stuck(5)).unwrap();
+        range.complement();
+    }
+
+    #[test]
+    fn test_new_validated_valid() {
+        let range = crate::ranges::UnaryRange::new_validated(Tens(10), Tens(50));
+        assert_eq!(
+            Some((Tens(10), Tens(50))),
+            range.map(|range| range.as_bounds())
+        );
+        assert!(crate::ranges::UnaryRange::new_validated(Tens(50), Tens(10)).is_none());
+    }
+
+    #[test]
+    fn test_checked_stepping() {
+        assert_eq!(Some(Tens(30)), Tens(20).checked_increment());
+        assert_eq!(Some(Tens(10)), Tens(20).checked_decrement());
+        assert_eq!(None, Tens::MAX_VAL.checked_increment());
+        assert_eq!(None, Tens::MIN_VAL.checked_decrement());
+        assert_eq!(None, u8::MAX.checked_increment());
+        assert_eq!(Some(254), u8::MAX.checked_decrement());
+    }
+}